@@ -1,8 +1,15 @@
-use proc_macro2::Span;
-use syn::{Ident, LitInt};
+use crate::sanitizer::SanitizerError;
+use proc_macro2::{Group, Literal, Span, TokenStream, TokenTree};
+use quote::quote;
+use regex_syntax::hir::Hir;
+use syn::{Attribute, Ident, LitInt, LitStr, Meta};
 
 pub struct Args {
     pub args: Vec<String>,
+    // whether each of `args` was a literal rather than a bare identifier
+    pub literals: Vec<bool>,
+    // `name = value` arguments
+    pub named: Vec<(String, String)>,
 }
 
 impl Args {
@@ -10,9 +17,65 @@ impl Args {
         self.args.len()
     }
 
-    pub fn new(args: Vec<String>) -> Self {
-        Self { args }
+    pub fn new(args: Vec<String>, literals: Vec<bool>, named: Vec<(String, String)>) -> Self {
+        Self {
+            args,
+            literals,
+            named,
+        }
     }
+
+    pub fn named(&self, name: &str) -> Option<&str> {
+        self.named
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    // make sure only the supported `name = value` arguments were given
+    pub fn check_named(&self, supported: &[&str]) -> Result<(), SanitizerError> {
+        if self
+            .named
+            .iter()
+            .all(|(key, _)| supported.contains(&key.as_str()))
+        {
+            Ok(())
+        } else {
+            Err(SanitizerError::InvalidArgument)
+        }
+    }
+}
+
+// syn only accepts literals on the right of `name = value`, so bare
+// identifiers like `unit = graphemes` are quoted before parsing
+pub fn parse_meta(attr: &Attribute) -> syn::Result<Meta> {
+    let mut attr = attr.clone();
+    attr.tokens = quote_bare_values(attr.tokens);
+    attr.parse_meta()
+}
+
+fn quote_bare_values(tokens: TokenStream) -> TokenStream {
+    let mut after_eq = false;
+    let mut quoted = Vec::new();
+    for token in tokens {
+        let token = match token {
+            TokenTree::Group(group) => {
+                let mut new_group =
+                    Group::new(group.delimiter(), quote_bare_values(group.stream()));
+                new_group.set_span(group.span());
+                TokenTree::Group(new_group)
+            }
+            TokenTree::Ident(ident) if after_eq && ident != "true" && ident != "false" => {
+                let mut literal = Literal::string(&ident.to_string());
+                literal.set_span(ident.span());
+                TokenTree::Literal(literal)
+            }
+            token => token,
+        };
+        after_eq = matches!(&token, TokenTree::Punct(punct) if punct.as_char() == '=');
+        quoted.push(token);
+    }
+    quoted.into_iter().collect()
 }
 
 pub struct ArgBuilder;
@@ -30,4 +93,208 @@ impl ArgBuilder {
         owned.retain(|character| !character.is_whitespace());
         Ident::new(&owned, Span::call_site())
     }
+
+    pub fn string(string: &str) -> LitStr {
+        LitStr::new(string, Span::call_site())
+    }
+
+    pub fn bool(boolean: &str) -> Result<bool, SanitizerError> {
+        boolean.parse().map_err(|_| SanitizerError::InvalidArgument)
+    }
+
+    pub fn region(region: &str) -> TokenStream {
+        let region = Self::ident(region);
+        quote! { sanitizer::Region::#region }
+    }
+
+    pub fn phone_format(format: &str) -> Result<TokenStream, SanitizerError> {
+        match format {
+            "e164" => Ok(quote! { sanitizer::PhoneFormat::E164 }),
+            "international" => Ok(quote! { sanitizer::PhoneFormat::International }),
+            "national" => Ok(quote! { sanitizer::PhoneFormat::National }),
+            "rfc3966" => Ok(quote! { sanitizer::PhoneFormat::Rfc3966 }),
+            _ => Err(SanitizerError::InvalidArgument),
+        }
+    }
+
+    pub fn truncate_unit(unit: &str) -> Result<TokenStream, SanitizerError> {
+        match unit {
+            "bytes" => Ok(quote! { sanitizer::TruncateUnit::Bytes }),
+            "chars" => Ok(quote! { sanitizer::TruncateUnit::Chars }),
+            "graphemes" => Ok(quote! { sanitizer::TruncateUnit::Graphemes }),
+            "width" => Ok(quote! { sanitizer::TruncateUnit::Width }),
+            _ => Err(SanitizerError::InvalidArgument),
+        }
+    }
+
+    pub fn word_case(case: &str) -> Result<TokenStream, SanitizerError> {
+        match case {
+            "title_case" => Ok(quote! { sanitizer::WordCase::Title }),
+            "pascal_case" => Ok(quote! { sanitizer::WordCase::Pascal }),
+            "train_case" => Ok(quote! { sanitizer::WordCase::Train }),
+            "sentence_case" => Ok(quote! { sanitizer::WordCase::Sentence }),
+            "lower_case_words" => Ok(quote! { sanitizer::WordCase::LowerWords }),
+            _ => Err(SanitizerError::InvalidArgument),
+        }
+    }
+
+    // a reference to the compiled regex, which is cached in a static so it
+    // is built once. The pattern is checked here so bad patterns fail the
+    // build instead of panicking at runtime
+    pub fn regex(pattern: &str) -> Result<TokenStream, SanitizerError> {
+        Self::parse_regex(pattern)?;
+        Ok(quote! {
+            {
+                static REGEX: std::sync::OnceLock<sanitizer::regex::Regex> =
+                    std::sync::OnceLock::new();
+                REGEX.get_or_init(|| sanitizer::regex::Regex::new(#pattern).unwrap())
+            }
+        })
+    }
+
+    // the index of a capture group of the pattern, 0 being the whole match
+    pub fn regex_group(pattern: &str, group: &str) -> Result<LitInt, SanitizerError> {
+        let index: usize = group
+            .trim()
+            .parse()
+            .map_err(|_| SanitizerError::InvalidArgument)?;
+        if index
+            > Self::parse_regex(pattern)?
+                .properties()
+                .explicit_captures_len()
+        {
+            return Err(SanitizerError::InvalidRegexGroup);
+        }
+        Ok(LitInt::new(&index.to_string(), Span::call_site()))
+    }
+
+    fn parse_regex(pattern: &str) -> Result<Hir, SanitizerError> {
+        regex_syntax::Parser::new()
+            .parse(pattern)
+            .map_err(|err| SanitizerError::InvalidRegex(err.to_string()))
+    }
+
+    pub fn html_policy(policy: &str) -> Result<TokenStream, SanitizerError> {
+        match policy {
+            "strip_all" => Ok(quote! { sanitizer::HtmlPolicy::StripAll }),
+            "basic" | "basic_formatting" => Ok(quote! { sanitizer::HtmlPolicy::BasicFormatting }),
+            "relaxed" => Ok(quote! { sanitizer::HtmlPolicy::Relaxed }),
+            _ => Err(SanitizerError::InvalidArgument),
+        }
+    }
+
+    pub fn newline_style(style: &str) -> Result<TokenStream, SanitizerError> {
+        match style {
+            "lf" => Ok(quote! { sanitizer::NewlineStyle::Lf }),
+            "crlf" => Ok(quote! { sanitizer::NewlineStyle::Crlf }),
+            _ => Err(SanitizerError::InvalidArgument),
+        }
+    }
+
+    // `keep(ascii_digit, "+")`, identifiers name a class, literals are
+    // sets of characters
+    pub fn char_class(class: &str, literal: bool) -> TokenStream {
+        if literal {
+            return quote! { sanitizer::CharClass::Set(String::from(#class)) };
+        }
+        let variant = match class {
+            "ascii" => Some(quote! { Ascii }),
+            "ascii_digit" => Some(quote! { AsciiDigit }),
+            "ascii_alpha" => Some(quote! { AsciiAlpha }),
+            "ascii_alnum" => Some(quote! { AsciiAlnum }),
+            "ascii_uppercase" => Some(quote! { AsciiUppercase }),
+            "ascii_lowercase" => Some(quote! { AsciiLowercase }),
+            "ascii_punctuation" => Some(quote! { AsciiPunctuation }),
+            "ascii_whitespace" => Some(quote! { AsciiWhitespace }),
+            "letter" => Some(quote! { Letter }),
+            "mark" => Some(quote! { Mark }),
+            "number" => Some(quote! { Number }),
+            "punctuation" => Some(quote! { Punctuation }),
+            "symbol" => Some(quote! { Symbol }),
+            "separator" => Some(quote! { Separator }),
+            "other" => Some(quote! { Other }),
+            "whitespace" => Some(quote! { Whitespace }),
+            _ => None,
+        };
+        if let Some(variant) = variant {
+            return quote! { sanitizer::CharClass::#variant };
+        }
+        if GENERAL_CATEGORIES.contains(&class) {
+            let name = Self::ident(&capitalize_words(class, ""));
+            quote! { sanitizer::CharClass::Category(sanitizer::GeneralCategory::#name) }
+        } else {
+            // anything else names a script, eg. `old_italic` is
+            // `Script::Old_Italic`, unknown scripts fail to compile
+            let name = Self::ident(&capitalize_words(class, "_"));
+            quote! { sanitizer::CharClass::Script(sanitizer::Script::#name) }
+        }
+    }
+}
+
+// capitalize the words of a snake case name and join them with separator
+fn capitalize_words(name: &str, separator: &str) -> String {
+    name.split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(separator)
+}
+
+static GENERAL_CATEGORIES: [&str; 30] = [
+    "uppercase_letter",
+    "lowercase_letter",
+    "titlecase_letter",
+    "modifier_letter",
+    "other_letter",
+    "nonspacing_mark",
+    "spacing_mark",
+    "enclosing_mark",
+    "decimal_number",
+    "letter_number",
+    "other_number",
+    "connector_punctuation",
+    "dash_punctuation",
+    "open_punctuation",
+    "close_punctuation",
+    "initial_punctuation",
+    "final_punctuation",
+    "other_punctuation",
+    "math_symbol",
+    "currency_symbol",
+    "modifier_symbol",
+    "other_symbol",
+    "space_separator",
+    "line_separator",
+    "paragraph_separator",
+    "control",
+    "format",
+    "private_use",
+    "surrogate",
+    "unassigned",
+];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn regex_errors() {
+        let err = ArgBuilder::regex("(unclosed").unwrap_err().to_string();
+        assert!(err.starts_with("Invalid regex pattern, "));
+        assert!(err.contains("unclosed group"));
+        assert!(ArgBuilder::regex_group(r"ORD-(\d+)", "1").is_ok());
+        assert!(matches!(
+            ArgBuilder::regex_group(r"ORD-(\d+)", "2"),
+            Err(SanitizerError::InvalidRegexGroup)
+        ));
+        assert!(matches!(
+            ArgBuilder::regex_group("x", "a"),
+            Err(SanitizerError::InvalidArgument)
+        ));
+    }
 }
@@ -1,4 +1,4 @@
-use crate::arg::Args;
+use crate::arg::{ArgBuilder, Args};
 use crate::sanitizer::SanitizerError;
 use crate::sanitizers::*;
 use crate::type_ident::TypeIdent;
@@ -27,37 +27,141 @@ pub fn sanitizer_function_body(
     }
 }
 
-pub fn methods_layout(list: &Vec<NestedMeta>, type_of_field: TypeIdent) -> TokenStream {
+pub fn methods_layout(
+    list: &Vec<NestedMeta>,
+    type_of_field: TypeIdent,
+    is_enum: bool,
+) -> TokenStream {
     let mut methods = quote! {};
+    // the region is read from the input before the phone number is
+    // formatted, as national formats may no longer be parsed correctly
+    let region_position = list
+        .iter()
+        .position(is_phone_sanitizer)
+        .or_else(|| list.iter().position(|meta| region_into(meta).is_some()));
+    let region_calls: TokenStream = list
+        .iter()
+        .filter_map(region_into)
+        .map(
+            |target| match region_into_body(list, &target, &type_of_field, is_enum) {
+                Ok(body) => body,
+                Err(err) => {
+                    let err = format!("{}: region_into", err);
+                    quote! {
+                        compile_error!(#err);
+                    }
+                }
+            },
+        )
+        .collect();
 
-    methods.append_all(list.iter().map(|meta| {
+    methods.append_all(list.iter().enumerate().map(|(position, meta)| {
+        let mut calls = quote! {};
+        if region_position == Some(position) {
+            calls.append_all(region_calls.clone());
+        }
+        if region_into(meta).is_some() {
+            return calls;
+        }
         let list = meta_list(meta);
         if let Ok(meta) = list {
             let res_body = sanitizer_function_body(&meta, type_of_field.clone());
             if let Ok(body) = res_body {
-                quote! {
+                calls.append_all(quote! {
                     instance.#body;
-                }
+                });
             } else {
                 let meta_ident = format!(
                     "{}: {}",
                     res_body.err().unwrap().to_string(),
                     meta.to_string()
                 );
-                quote! {
+                calls.append_all(quote! {
                     compile_error!(#meta_ident);
-                }
+                });
             }
         } else {
             let err = list.err().unwrap().to_string();
-            quote! {
+            calls.append_all(quote! {
                 compile_error!(#err);
-            }
+            });
         }
+        calls
     }));
     methods
 }
 
+// clears the sibling fields named by `region_into`, so they are `None` when
+// an optional phone number is `None`
+pub fn region_into_resets(list: &[NestedMeta]) -> TokenStream {
+    let targets = list
+        .iter()
+        .filter_map(region_into)
+        .map(|target| ArgBuilder::ident(&target));
+    quote! {
+        #(self.#targets = None;)*
+    }
+}
+
+// the sibling field named by `region_into = "field"`, if this is that option
+fn region_into(meta: &NestedMeta) -> Option<String> {
+    if let NestedMeta::Meta(Meta::NameValue(name_value)) = meta {
+        if name_value.path.is_ident("region_into") {
+            if let Lit::Str(field) = &name_value.lit {
+                return Some(field.value());
+            }
+        }
+    }
+    None
+}
+
+fn is_phone_sanitizer(meta: &NestedMeta) -> bool {
+    meta_list(meta).is_ok_and(|sanitizer| {
+        let name = sanitizer.to_string();
+        name == "e164" || name == "phone"
+    })
+}
+
+// writes the region of the phone number into the sibling field, using the
+// region given to e164 or phone (if any) to parse national numbers
+fn region_into_body(
+    list: &Vec<NestedMeta>,
+    target: &str,
+    type_of_field: &TypeIdent,
+    is_enum: bool,
+) -> Result<TokenStream, SanitizerError> {
+    if is_enum {
+        return Err(SanitizerError::SiblingFieldOnEnum);
+    }
+    if !type_of_field.is_string() {
+        return Err(SanitizerError::InvalidFieldType);
+    }
+    let region = list
+        .iter()
+        .find(|meta| is_phone_sanitizer(meta))
+        .and_then(|meta| meta_list(meta).ok())
+        .filter(PathOrList::has_args)
+        .and_then(|sanitizer| {
+            let args = sanitizer.get_args();
+            // e164(region) and phone(format, region)
+            match (sanitizer.to_string().as_str(), args.args.as_slice()) {
+                ("e164", [region]) | ("phone", [_, region]) => Some(ArgBuilder::region(region)),
+                _ => None,
+            }
+        });
+    let region = match region {
+        Some(region) => quote! { Some(#region) },
+        None => quote! { None },
+    };
+    let target = ArgBuilder::ident(target);
+    Ok(quote! {
+        self.#target = instance
+            .phone_info(#region)
+            .and_then(|info| info.region)
+            .map(|region| region.as_ref().to_owned());
+    })
+}
+
 // helper function to get the list item as ident
 pub fn meta_list(meta: &NestedMeta) -> Result<PathOrList, SanitizerError> {
     match meta {
@@ -72,14 +176,22 @@ pub fn meta_list(meta: &NestedMeta) -> Result<PathOrList, SanitizerError> {
             Meta::List(list) => {
                 if let Some(list_ident) = list.path.get_ident() {
                     let mut vec = Vec::new();
+                    let mut literals = Vec::new();
+                    let mut named = Vec::new();
                     for args in list.nested.clone() {
-                        if let Some(list_ident) = get_first_arg(&args) {
+                        if let Some(name_value) = get_named_arg(&args) {
+                            named.push(name_value);
+                        } else if let Some(list_ident) = get_first_arg(&args) {
                             vec.push(list_ident);
+                            literals.push(matches!(args, NestedMeta::Lit(_)));
                         } else {
                             return Err(SanitizerError::Only64BitInt);
                         }
                     }
-                    return Ok(PathOrList::List(list_ident.clone(), Args::new(vec)));
+                    return Ok(PathOrList::List(
+                        list_ident.clone(),
+                        Args::new(vec, literals, named),
+                    ));
                 } else {
                     Err(SanitizerError::MacrosWithListOnly)
                 }
@@ -92,10 +204,7 @@ pub fn meta_list(meta: &NestedMeta) -> Result<PathOrList, SanitizerError> {
 
 pub fn get_first_arg(meta: &NestedMeta) -> Option<String> {
     match meta {
-        NestedMeta::Lit(literal) => match literal {
-            Lit::Int(integer) => Some(integer.to_string()),
-            _ => None,
-        },
+        NestedMeta::Lit(literal) => lit_value(literal),
         NestedMeta::Meta(meta) => match meta {
             Meta::Path(path) => Some(path.segments.last().unwrap().ident.to_string()),
             _ => None,
@@ -103,6 +212,26 @@ pub fn get_first_arg(meta: &NestedMeta) -> Option<String> {
     }
 }
 
+// `name = value` arguments, eg. clamp(10, unit = graphemes)
+pub fn get_named_arg(meta: &NestedMeta) -> Option<(String, String)> {
+    match meta {
+        NestedMeta::Meta(Meta::NameValue(name_value)) => {
+            let name = name_value.path.get_ident()?.to_string();
+            Some((name, lit_value(&name_value.lit)?))
+        }
+        _ => None,
+    }
+}
+
+fn lit_value(literal: &Lit) -> Option<String> {
+    match literal {
+        Lit::Int(integer) => Some(integer.to_string()),
+        Lit::Str(string) => Some(string.value()),
+        Lit::Bool(boolean) => Some(boolean.value.to_string()),
+        _ => None,
+    }
+}
+
 impl PathOrList {
     pub fn has_args(&self) -> bool {
         if let Self::List(_, _) = self {
@@ -63,18 +63,97 @@ mod type_ident;
 /// # Available sanitizers
 ///
 /// - **trim**: Trims the string.
+/// - **trim_start**, **trim_end**: Trim the start or the end of the string.
+/// - **trim_matches(chars)**: Trim the given characters from both ends, eg. `trim_matches("-_")`.
+/// - **collapse_whitespace**: Replace every run of Unicode whitespace with a single space.
+/// - **normalize_whitespace**: Like collapse_whitespace, but keeps line and paragraph breaks unless
+/// `keep_newlines = false` is given.
+/// - **normalize_newlines**: Convert every line ending to `\n`, or to `\r\n` with
+/// `normalize_newlines(crlf)`.
+/// - **max_consecutive_newlines(max)**: Limit runs of line breaks to max.
+/// - **strip_trailing_whitespace_per_line**: Remove the whitespace at the end of every line.
+/// - **strip_control**: Remove C0 and C1 control characters, `strip_control(keep = "\n\t")` keeps
+/// the given characters.
+/// - **strip_invisible**: Remove invisible characters such as zero width spaces and soft hyphens.
+/// - **strip_bidi_controls**: Remove bidirectional formatting characters.
 /// - **numeric**: Remove numeric items from the string.
 /// - **alphanumeric**: Remove alphanumeric items from the string.
+/// - **ascii_numeric**: Remove everything except the ASCII digits `0-9`.
+/// - **ascii_alphanumeric**: Remove everything except ASCII letters and digits.
+/// - **keep(classes)**: Remove every character not in one of the classes, eg.
+/// `keep(ascii_digit, "+")`. Classes are ASCII classes (`ascii_digit`, `ascii_alnum`, ...),
+/// general category groups (`letter`, `number`, `punctuation`, ...) or categories
+/// (`decimal_number`, ...), scripts (`latin`, `cyrillic`, ...) or sets of characters like `"a-z_"`.
+/// - **remove(classes)**: Remove every character in one of the classes, eg. `remove(punctuation)`.
 /// - **lower_case**: Convert input to lower case.
 /// - **upper_case**: Convert input to upper case.
+/// - **lower_case(locale = "tr")**, **upper_case(locale = "tr")**: Convert input to lower or upper
+/// case following the rules of the language.
+/// - **lower_case(locale_from = "field")**, **upper_case(locale_from = "field")**: Like above,
+/// reading the language from a sibling `String` or `Locale` field. Struct fields only.
+/// - **case_fold**: Apply Unicode case folding, for caseless comparisons.
+/// - **identifier_key**: Convert input to a lookup key for identifiers (NFKC_Casefold).
+/// - **to_ascii**: Transliterate input to ASCII, eg. "Crème" becomes "Creme".
+/// - **transliterate(replacement)**: Transliterate input to ASCII, replacing characters without
+/// an approximation with replacement.
+/// - **slugify**: Convert input to a URL slug, eg. "Crème Brûlée!" becomes "creme-brulee".
+/// - **slugify(sep = "_", max = 60)**: Convert input to a URL slug using the separator, cutting
+/// it at a word boundary to at most max bytes. Both arguments are optional.
 /// - **camel_case**: Convert input to camel case.
 /// - **snake_case**: Convert input to snake case.
 /// - **kebab_case**: Convert input to kebab case.
 /// - **screaming_kebab_case**: Convert input to shouty kebab case.
+/// - **person_name**: Capitalize a personal name, eg. "o'brien" becomes "O'Brien". Exceptions can be
+/// given as arguments, eg. `person_name("DeVito")`.
+/// - **nfc**, **nfd**, **nfkc**, **nfkd**: Convert input to the Unicode normalization form.
+/// - **title_case**, **pascal_case**, **train_case**, **sentence_case**, **lower_case_words**:
+/// Convert input to the case, optionally keeping the given acronyms, eg. `title_case("HTTP", "ID")`.
 /// - **e164**: Convert a valid phone number to the e164 international standard, panic if invalid phone number.
+/// - **e164(region)**: Like e164, reading national numbers as belonging to the region, eg. `e164(GB)`.
+/// - **region_into = "field"**: Write the region of the phone number (eg. `"GB"`) into the
+/// sibling `Option<String>` field, or `None` if it cannot be determined. The region is read
+/// before the number is formatted. Struct fields only.
+/// - **phone(format)**, **phone(format, region)**: Format a valid phone number as `e164`,
+/// `international`, `national` or `rfc3966`.
 /// - **clamp(min, max)**: Limit an integer input to this region of min to max.
-/// - **clamp(max)**: Cut the string if it exceeds max.
+/// - **clamp(max)**: Cut the string if it exceeds max bytes, without splitting characters.
+/// - **clamp(max, unit = graphemes, ellipsis = "…")**: Cut the string if it exceeds max counted in
+/// `bytes`, `chars`, `graphemes` or (display) `width`, ending it with the optional ellipsis.
+/// - **truncate_words(max, ellipsis)**: Cut the string to at most max characters without breaking
+/// words, ending it with the optional ellipsis, eg. `truncate_words(80, "…")`.
 /// - **screaming_snake_case**: Convert input to screaming snake case.
+/// - **html_escape**: Escape `&`, `<`, `>`, `"` and `'` as HTML entities.
+/// - **html_unescape**: Decode HTML entities, including double encoded ones like `&amp;amp;`.
+/// - **strip_html**: Convert HTML to plain text, block elements start new lines.
+/// - **strip_markdown**: Convert Markdown to plain text, needs the `markdown` feature of sanitizer.
+/// - **html(policy)**: Make HTML safe by keeping only the tags and attributes allowed by the policy,
+/// `strip_all`, `basic` or `relaxed`. Needs the `html` feature of sanitizer.
+/// - **csv_safe**: Prefix values which spreadsheets would run as formulas with `'`. Use
+/// `csv_safe(quote)` to output quoted CSV fields and `csv_safe(preserve_numbers)` to leave numbers
+/// like "-5" unchanged.
+/// - **log_safe**: Escape backslashes and line breaks and remove ANSI escape sequences and control
+/// characters, so the value can be logged, cutting it to 1024 characters or to max with
+/// `log_safe(max)`.
+/// - **filename**: Make input safe to use as a filename, removing path separators, traversal,
+/// control characters and reserved names, and cutting it to 255 bytes or to max with
+/// `filename(max)` while keeping the extension.
+/// - **relative_path**: Normalise a relative path, which cannot leave its root directory.
+/// - **email**: Normalise an email address, lowercasing and IDNA encoding the domain only.
+/// - **email_canonical**: Convert an email address to a key for finding duplicates, applying
+/// provider rules such as Gmail ignoring dots and `+tags`.
+/// - **hostname**: Normalise a domain or hostname, lowercasing it, removing a trailing dot and
+/// IDNA encoding Unicode labels.
+/// - **hostname_unicode**: Normalise a hostname and decode its punycode labels for display.
+/// - **strip_www**: Remove a leading `www.` of a hostname.
+/// - **url**: Normalise an http or https URL, adding a missing scheme and removing tracking
+/// parameters. Options are `url(default_scheme = "http", strip_fragment, sort_query,
+/// remove_params = "utm_*,ref")`.
+/// - **regex_replace(pattern, replacement)**: Replace all matches of the regex, needs the `regex`
+/// feature of sanitizer. Patterns are checked at compile time.
+/// - **regex_keep(pattern)**: Keep only the matches of the regex.
+/// - **regex_extract(pattern, group)**: Replace input with the capture group of the first match, or
+/// the whole match without a group. Input without a match becomes empty. The group must exist in
+/// the pattern.
 /// - **custom(function)**: A custom function that is called to sanitize a field
 /// according to any other way.
 #[proc_macro_derive(Sanitizer, attributes(sanitizer))]
@@ -89,11 +168,13 @@ pub fn sanitize(input: TokenStream) -> TokenStream {
             let mut body = quote! {};
             match field {
                 TypeOrNested::Type(field, type_ident) => {
-                    let sanitizer_calls = sanitizer_gen::methods_layout(r.1, type_ident.clone());
+                    let sanitizer_calls =
+                        sanitizer_gen::methods_layout(r.1, type_ident.clone(), val.is_enum());
                     let stream: TokenStream2;
                     if val.is_enum() {
                         stream = EnumGen::new(field.clone(), type_ident).body(sanitizer_calls);
                     } else {
+                        body.append_all(sanitizer_gen::region_into_resets(r.1));
                         stream = StructGen::new(field.clone(), type_ident).body(sanitizer_calls);
                     }
                     body.append_all(stream)
@@ -1,3 +1,4 @@
+use crate::arg::parse_meta;
 use crate::type_ident::{TypeIdent, TypeOrNested};
 use std::collections::BTreeMap;
 use std::convert::TryFrom;
@@ -17,9 +18,14 @@ pub enum SanitizerError {
     MacrosWithListOnly,
     InvalidSanitizer,
     WrongArguments,
+    InvalidArgument,
     Only64BitInt,
     EnumsUnamedFields,
     OnlyOptionTSupported,
+    SiblingFieldOnEnum,
+    // the message of the regex parser
+    InvalidRegex(String),
+    InvalidRegexGroup,
 }
 
 // the type of map where we store the fields with the lints
@@ -63,7 +69,7 @@ pub fn populate_map_struct(
                 push = true;
                 field_type = TypeIdent::try_from(field.ty.clone())?;
                 type_field.set_type(field_type.clone());
-                let meta = attr.parse_meta().unwrap();
+                let meta = parse_meta(attr).unwrap();
                 match meta {
                     // the attribute should be a list. for eg. sanitise(options)
                     Meta::List(ref list) => {
@@ -107,7 +113,7 @@ pub fn populate_map_enum(
         let mut type_field = TypeOrNested::Type(variant.clone().ident, field_type);
         let mut push = false;
         for attr in variant.attrs.iter() {
-            let meta = attr.parse_meta().unwrap();
+            let meta = parse_meta(attr).unwrap();
             if attr.path.is_ident("sanitizer") {
                 push = true;
                 match meta {
@@ -186,9 +192,13 @@ impl Display for SanitizerError {
             }
             Self::InvalidSanitizer => "Invalid sanitizer",
             Self::WrongArguments => "Wrong number of arguments",
+            Self::InvalidArgument => "Invalid argument",
             Self::Only64BitInt => "The argument can be only 64 bit int",
             Self::EnumsUnamedFields => "Enums can contain only unnamed field",
             Self::OnlyOptionTSupported => "Only Option<T> and Option<Option<T>> supported for now",
+            Self::SiblingFieldOnEnum => "Enum variants have no sibling fields to write to",
+            Self::InvalidRegex(message) => return write!(f, "Invalid regex pattern, {}", message),
+            Self::InvalidRegexGroup => "The regex has no capture group with this index",
         };
         write!(f, "{}", case)
     }
@@ -5,6 +5,13 @@ use proc_macro2::TokenStream;
 use quote::quote;
 
 macro_rules! sanitizer_with_arg {
+    ( $sanitizer : expr, $body : expr ) => {
+        if $sanitizer.has_args() {
+            $body
+        } else {
+            Err(SanitizerError::WrongArguments)
+        }
+    };
     ( $sanitizer : expr, $method_name : ident, $arg : expr, $func_call : ident ) => {
         if $sanitizer.has_args() {
             if $sanitizer.get_args().len() == 1 {
@@ -24,18 +31,376 @@ macro_rules! sanitizer_with_arg {
 pub fn get_string_sanitizers(sanitizer: &PathOrList) -> Result<TokenStream, SanitizerError> {
     match sanitizer.to_string().as_str() {
         "trim" => Ok(quote! { trim() }),
+        "trim_start" => Ok(quote! { trim_start() }),
+        "trim_end" => Ok(quote! { trim_end() }),
+        "trim_matches" => {
+            sanitizer_with_arg!(
+                sanitizer,
+                string,
+                &sanitizer.get_args().args[0],
+                trim_matches
+            )
+        }
+        "collapse_whitespace" => Ok(quote! { collapse_whitespace() }),
+        "normalize_whitespace" => {
+            let keep_newlines = if sanitizer.has_args() {
+                let args = sanitizer.get_args();
+                args.check_named(&["keep_newlines"])?;
+                ArgBuilder::bool(args.named("keep_newlines").unwrap_or("true"))?
+            } else {
+                true
+            };
+            Ok(quote! { normalize_whitespace(#keep_newlines) })
+        }
+        "normalize_newlines" => {
+            let style = if sanitizer.has_args() {
+                match sanitizer.get_args().args.as_slice() {
+                    [style] => ArgBuilder::newline_style(style)?,
+                    _ => return Err(SanitizerError::WrongArguments),
+                }
+            } else {
+                ArgBuilder::newline_style("lf")?
+            };
+            Ok(quote! { normalize_newlines(#style) })
+        }
+        "max_consecutive_newlines" => sanitizer_with_arg!(
+            sanitizer,
+            int,
+            &sanitizer.get_args().args[0],
+            max_consecutive_newlines
+        ),
+        "strip_trailing_whitespace_per_line" => Ok(quote! { strip_trailing_whitespace_per_line() }),
+        "strip_control" => {
+            if sanitizer.has_args() {
+                let args = sanitizer.get_args();
+                args.check_named(&["keep"])?;
+                match (args.len(), args.named("keep")) {
+                    (0, Some(keep)) => {
+                        let keep = ArgBuilder::string(keep);
+                        Ok(quote! { strip_control_except(#keep) })
+                    }
+                    _ => Err(SanitizerError::WrongArguments),
+                }
+            } else {
+                Ok(quote! { strip_control() })
+            }
+        }
+        "strip_invisible" => Ok(quote! { strip_invisible() }),
+        "strip_bidi_controls" => Ok(quote! { strip_bidi_controls() }),
         "numeric" => Ok(quote! { numeric() }),
         "alphanumeric" => Ok(quote! { alphanumeric() }),
-        "lower_case" => Ok(quote! { to_lowercase() }),
-        "upper_case" => Ok(quote! { to_uppercase() }),
+        "ascii_numeric" => Ok(quote! { ascii_numeric() }),
+        "ascii_alphanumeric" => Ok(quote! { ascii_alphanumeric() }),
+        "keep" | "remove" => {
+            sanitizer_with_arg!(sanitizer, {
+                let args = sanitizer.get_args();
+                args.check_named(&[])?;
+                if args.args.is_empty() {
+                    return Err(SanitizerError::WrongArguments);
+                }
+                let classes = args
+                    .args
+                    .iter()
+                    .zip(&args.literals)
+                    .map(|(class, literal)| ArgBuilder::char_class(class, *literal));
+                let method = ArgBuilder::ident(&sanitizer.to_string());
+                Ok(quote! { #method(&[#(#classes),*]) })
+            })
+        }
+        "lower_case" | "upper_case" => {
+            let locale = if sanitizer.has_args() {
+                let args = sanitizer.get_args();
+                args.check_named(&["locale", "locale_from"])?;
+                if args.len() != 0 {
+                    return Err(SanitizerError::WrongArguments);
+                }
+                // the locale is either a literal language tag, or read at
+                // runtime from a sibling field holding a tag or a `Locale`
+                match (args.named("locale"), args.named("locale_from")) {
+                    (Some(locale), None) => Some(quote! { #locale }),
+                    (None, Some(field)) => {
+                        let field = ArgBuilder::ident(field);
+                        Some(quote! { &self.#field })
+                    }
+                    (None, None) => None,
+                    (Some(_), Some(_)) => return Err(SanitizerError::WrongArguments),
+                }
+            } else {
+                None
+            };
+            let lower = sanitizer.to_string() == "lower_case";
+            match (locale, lower) {
+                (Some(locale), true) => Ok(quote! { to_lowercase_locale(#locale) }),
+                (Some(locale), false) => Ok(quote! { to_uppercase_locale(#locale) }),
+                (None, true) => Ok(quote! { to_lowercase() }),
+                (None, false) => Ok(quote! { to_uppercase() }),
+            }
+        }
+        "case_fold" => Ok(quote! { case_fold() }),
+        "identifier_key" => Ok(quote! { identifier_key() }),
+        "to_ascii" => Ok(quote! { to_ascii() }),
+        "transliterate" => sanitizer_with_arg!(
+            sanitizer,
+            string,
+            &sanitizer.get_args().args[0],
+            transliterate
+        ),
+        "slugify" => {
+            if sanitizer.has_args() {
+                let args = sanitizer.get_args();
+                args.check_named(&["sep", "max"])?;
+                if !args.args.is_empty() {
+                    return Err(SanitizerError::WrongArguments);
+                }
+                let separator = ArgBuilder::string(args.named("sep").unwrap_or("-"));
+                let max = match args.named("max") {
+                    Some(max) => {
+                        let max = ArgBuilder::int(max);
+                        quote! { Some(#max) }
+                    }
+                    None => quote! { None },
+                };
+                Ok(quote! { slugify_with(#separator, #max) })
+            } else {
+                Ok(quote! { slugify() })
+            }
+        }
         "camel_case" => Ok(quote! { to_camel_case() }),
         "snake_case" => Ok(quote! { to_snake_case() }),
         "kebab_case" => Ok(quote! { to_kebab_case() }),
         "screaming_kebab_case" => Ok(quote! { to_screaming_kebab_case() }),
         "screaming_snake_case" => Ok(quote! { to_screaming_snakecase() }),
-        "e164" => Ok(quote! { e164() }),
+        "title_case" | "pascal_case" | "train_case" | "sentence_case" | "lower_case_words" => {
+            let name = sanitizer.to_string();
+            if sanitizer.has_args() {
+                let case = ArgBuilder::word_case(&name)?;
+                let acronyms = sanitizer
+                    .get_args()
+                    .args
+                    .iter()
+                    .map(|acronym| ArgBuilder::string(acronym));
+                Ok(quote! { to_case_with_acronyms(#case, &[#(#acronyms),*]) })
+            } else {
+                let method = ArgBuilder::ident(&format!("to_{}", name));
+                Ok(quote! { #method() })
+            }
+        }
+        "person_name" => {
+            if sanitizer.has_args() {
+                let exceptions = sanitizer
+                    .get_args()
+                    .args
+                    .iter()
+                    .map(|exception| ArgBuilder::string(exception));
+                Ok(quote! { person_name_with_exceptions(&[#(#exceptions),*]) })
+            } else {
+                Ok(quote! { person_name() })
+            }
+        }
+        "nfc" => Ok(quote! { nfc() }),
+        "nfd" => Ok(quote! { nfd() }),
+        "nfkc" => Ok(quote! { nfkc() }),
+        "nfkd" => Ok(quote! { nfkd() }),
+        "e164" => {
+            if sanitizer.has_args() {
+                sanitizer_with_arg!(
+                    sanitizer,
+                    region,
+                    &sanitizer.get_args().args[0],
+                    e164_with_region
+                )
+            } else {
+                Ok(quote! { e164() })
+            }
+        }
+        "phone" => {
+            sanitizer_with_arg!(sanitizer, {
+                match sanitizer.get_args().args.as_slice() {
+                    [format] => {
+                        let format = ArgBuilder::phone_format(format)?;
+                        Ok(quote! { phone_number(None, #format) })
+                    }
+                    [format, region] => {
+                        let format = ArgBuilder::phone_format(format)?;
+                        let region = ArgBuilder::region(region);
+                        Ok(quote! { phone_number(Some(#region), #format) })
+                    }
+                    _ => Err(SanitizerError::WrongArguments),
+                }
+            })
+        }
         "clamp" => {
-            sanitizer_with_arg!(sanitizer, int, &sanitizer.get_args().args[0], cut)
+            sanitizer_with_arg!(sanitizer, {
+                let args = sanitizer.get_args();
+                args.check_named(&["unit", "ellipsis"])?;
+                match args.args.as_slice() {
+                    [limit] => {
+                        let limit = ArgBuilder::int(limit);
+                        let unit =
+                            ArgBuilder::truncate_unit(args.named("unit").unwrap_or("bytes"))?;
+                        if let Some(ellipsis) = args.named("ellipsis") {
+                            let ellipsis = ArgBuilder::string(ellipsis);
+                            Ok(quote! { truncate_with_ellipsis(#limit, #unit, #ellipsis) })
+                        } else {
+                            Ok(quote! { truncate(#limit, #unit) })
+                        }
+                    }
+                    _ => Err(SanitizerError::WrongArguments),
+                }
+            })
+        }
+        "truncate_words" => {
+            sanitizer_with_arg!(sanitizer, {
+                let (max, ellipsis) = match sanitizer.get_args().args.as_slice() {
+                    [max] => (max, ""),
+                    [max, ellipsis] => (max, ellipsis.as_str()),
+                    _ => return Err(SanitizerError::WrongArguments),
+                };
+                let max = ArgBuilder::int(max);
+                let ellipsis = ArgBuilder::string(ellipsis);
+                Ok(quote! { truncate_words(#max, #ellipsis) })
+            })
+        }
+        "html_escape" => Ok(quote! { html_escape() }),
+        "html_unescape" => Ok(quote! { html_unescape() }),
+        "strip_html" => Ok(quote! { strip_html() }),
+        "strip_markdown" => Ok(quote! { strip_markdown() }),
+        "html" => {
+            sanitizer_with_arg!(sanitizer, {
+                match sanitizer.get_args().args.as_slice() {
+                    [policy] => {
+                        let policy = ArgBuilder::html_policy(policy)?;
+                        Ok(quote! { html_sanitize(#policy) })
+                    }
+                    _ => Err(SanitizerError::WrongArguments),
+                }
+            })
+        }
+        "regex_replace" => {
+            sanitizer_with_arg!(sanitizer, {
+                match sanitizer.get_args().args.as_slice() {
+                    [pattern, replacement] => {
+                        let regex = ArgBuilder::regex(pattern)?;
+                        let replacement = ArgBuilder::string(replacement);
+                        Ok(quote! { regex_replace(#regex, #replacement) })
+                    }
+                    _ => Err(SanitizerError::WrongArguments),
+                }
+            })
+        }
+        "regex_keep" => {
+            sanitizer_with_arg!(sanitizer, {
+                match sanitizer.get_args().args.as_slice() {
+                    [pattern] => {
+                        let regex = ArgBuilder::regex(pattern)?;
+                        Ok(quote! { regex_keep(#regex) })
+                    }
+                    _ => Err(SanitizerError::WrongArguments),
+                }
+            })
+        }
+        "regex_extract" => {
+            sanitizer_with_arg!(sanitizer, {
+                let (pattern, group) = match sanitizer.get_args().args.as_slice() {
+                    [pattern] => (pattern, "0"),
+                    [pattern, group] => (pattern, group.as_str()),
+                    _ => return Err(SanitizerError::WrongArguments),
+                };
+                let regex = ArgBuilder::regex(pattern)?;
+                let group = ArgBuilder::regex_group(pattern, group)?;
+                Ok(quote! { regex_extract(#regex, #group) })
+            })
+        }
+        "csv_safe" => {
+            if sanitizer.has_args() {
+                let mut mode = quote! { sanitizer::CsvSafeMode::Prefix };
+                let mut preserve_numbers = false;
+                for arg in sanitizer.get_args().args.iter() {
+                    match arg.as_str() {
+                        "prefix" => mode = quote! { sanitizer::CsvSafeMode::Prefix },
+                        "quote" => mode = quote! { sanitizer::CsvSafeMode::Quote },
+                        "preserve_numbers" => preserve_numbers = true,
+                        _ => return Err(SanitizerError::InvalidArgument),
+                    }
+                }
+                Ok(quote! {
+                    csv_safe_with(sanitizer::CsvSafeOptions {
+                        mode: #mode,
+                        preserve_numbers: #preserve_numbers,
+                    })
+                })
+            } else {
+                Ok(quote! { csv_safe() })
+            }
+        }
+        "log_safe" => {
+            if sanitizer.has_args() {
+                match sanitizer.get_args().args.as_slice() {
+                    [max] => {
+                        let max = ArgBuilder::int(max);
+                        Ok(quote! { log_safe_with_limit(#max) })
+                    }
+                    _ => Err(SanitizerError::WrongArguments),
+                }
+            } else {
+                Ok(quote! { log_safe() })
+            }
+        }
+        "filename" => {
+            if sanitizer.has_args() {
+                match sanitizer.get_args().args.as_slice() {
+                    [max] => {
+                        let max = ArgBuilder::int(max);
+                        Ok(quote! { filename_with_limit(#max) })
+                    }
+                    _ => Err(SanitizerError::WrongArguments),
+                }
+            } else {
+                Ok(quote! { filename() })
+            }
+        }
+        "relative_path" => Ok(quote! { relative_path() }),
+        "email" => Ok(quote! { email() }),
+        "email_canonical" => Ok(quote! { email_canonical() }),
+        "hostname" => Ok(quote! { hostname() }),
+        "hostname_unicode" => Ok(quote! { hostname_unicode() }),
+        "strip_www" => Ok(quote! { strip_www() }),
+        "url" => {
+            if sanitizer.has_args() {
+                let args = sanitizer.get_args();
+                args.check_named(&["default_scheme", "remove_params"])?;
+                let mut strip_fragment = false;
+                let mut sort_query = false;
+                for arg in args.args.iter() {
+                    match arg.as_str() {
+                        "strip_fragment" => strip_fragment = true,
+                        "sort_query" => sort_query = true,
+                        _ => return Err(SanitizerError::InvalidArgument),
+                    }
+                }
+                let default_scheme = args.named("default_scheme").unwrap_or("https");
+                // a comma separated list, eg. remove_params = "utm_*,ref"
+                let remove_params = match args.named("remove_params") {
+                    Some(params) => {
+                        let params = params
+                            .split(',')
+                            .map(str::trim)
+                            .filter(|param| !param.is_empty());
+                        quote! { &[#(#params),*] }
+                    }
+                    None => quote! { sanitizer::TRACKING_PARAMS },
+                };
+                Ok(quote! {
+                    url_with(&sanitizer::UrlOptions {
+                        default_scheme: #default_scheme,
+                        strip_fragment: #strip_fragment,
+                        sort_query: #sort_query,
+                        remove_params: #remove_params,
+                    })
+                })
+            } else {
+                Ok(quote! { url() })
+            }
         }
         "custom" => {
             sanitizer_with_arg!(
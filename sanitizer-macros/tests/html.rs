@@ -0,0 +1,24 @@
+use sanitizer::prelude::*;
+
+#[derive(Sanitizer)]
+struct HtmlTest {
+    #[sanitizer(html(strip_all))]
+    strip_all: String,
+    #[sanitizer(html(basic))]
+    basic: String,
+    #[sanitizer(trim, html(relaxed))]
+    relaxed: Option<String>,
+}
+
+#[test]
+fn html_policies() {
+    let mut instance = HtmlTest {
+        strip_all: String::from("<h1>Hello</h1> <b>World</b>"),
+        basic: String::from("<h1>Hello</h1> <b onmouseover=\"x()\">World</b><!-- x -->"),
+        relaxed: Some(String::from(" <h1>Hello</h1><script>x()</script> ")),
+    };
+    instance.sanitize();
+    assert_eq!(instance.strip_all, "Hello World");
+    assert_eq!(instance.basic, "Hello <b>World</b>");
+    assert_eq!(instance.relaxed, Some(String::from("<h1>Hello</h1>")));
+}
@@ -0,0 +1,24 @@
+use sanitizer::prelude::*;
+
+#[derive(Sanitizer)]
+struct MarkupTest {
+    #[sanitizer(strip_html)]
+    html: String,
+    #[sanitizer(strip_markdown)]
+    markdown: String,
+    #[sanitizer(strip_markdown, clamp(12, unit = chars, ellipsis = "…"))]
+    preview: Option<String>,
+}
+
+#[test]
+fn strip_markup() {
+    let mut instance = MarkupTest {
+        html: String::from("<p>Hello&nbsp;<em>World</em></p><p>Bye</p>"),
+        markdown: String::from("## Hello *World*\n\nSee [the docs](https://docs.rs)."),
+        preview: Some(String::from("**Breaking:** something happened")),
+    };
+    instance.sanitize();
+    assert_eq!(instance.html, "Hello\u{a0}World\n\nBye");
+    assert_eq!(instance.markdown, "Hello World\n\nSee the docs.");
+    assert_eq!(instance.preview, Some(String::from("Breaking: s…")));
+}
@@ -0,0 +1,78 @@
+use sanitizer::prelude::*;
+
+#[derive(Sanitizer)]
+struct PhoneTest {
+    #[sanitizer(e164)]
+    e164: String,
+    #[sanitizer(e164(GB))]
+    e164_region: String,
+    #[sanitizer(phone(national))]
+    national: String,
+    #[sanitizer(phone(international, GB))]
+    international: String,
+    #[sanitizer(trim, phone(rfc3966, GB))]
+    rfc3966: Option<String>,
+}
+
+#[test]
+fn phone_formats() {
+    let mut instance = PhoneTest {
+        e164: String::from("020 7946 0018"),
+        e164_region: String::from("020 7946 0018"),
+        national: String::from("+44 20 7946 0018"),
+        international: String::from("020 7946 0018"),
+        rfc3966: Some(String::from(" 020 7946 0018 ")),
+    };
+    instance.sanitize();
+    assert_eq!(instance.e164, "020 7946 0018");
+    assert_eq!(instance.e164_region, "+442079460018");
+    assert_eq!(instance.national, "020 7946 0018");
+    assert_eq!(instance.international, "+44 20 7946 0018");
+    assert_eq!(instance.rfc3966, Some(String::from("tel:+44-20-7946-0018")));
+}
+
+#[derive(Sanitizer)]
+struct RegionTest {
+    #[sanitizer(e164, region_into = "phone_country")]
+    phone: String,
+    phone_country: Option<String>,
+    #[sanitizer(phone(national, GB), region_into = "national_country")]
+    national: Option<String>,
+    national_country: Option<String>,
+    #[sanitizer(phone(national), region_into = "format_country")]
+    format_only: String,
+    format_country: Option<String>,
+}
+
+#[test]
+fn phone_region_into() {
+    let mut instance = RegionTest {
+        phone: String::from("+1 (416) 555-0199"),
+        phone_country: None,
+        national: Some(String::from("+1 (416) 555-0199")),
+        national_country: None,
+        format_only: String::from("+44 20 7946 0018"),
+        format_country: None,
+    };
+    instance.sanitize();
+    assert_eq!(instance.phone, "+14165550199");
+    assert_eq!(instance.phone_country, Some(String::from("CA")));
+    assert_eq!(instance.national, Some(String::from("(416) 555-0199")));
+    assert_eq!(instance.national_country, Some(String::from("CA")));
+    assert_eq!(instance.format_only, "020 7946 0018");
+    assert_eq!(instance.format_country, Some(String::from("GB")));
+
+    let mut instance = RegionTest {
+        phone: String::from("Not a Phone Number"),
+        phone_country: Some(String::from("GB")),
+        national: None,
+        national_country: Some(String::from("GB")),
+        format_only: String::from("020 7946 0018"),
+        format_country: Some(String::from("GB")),
+    };
+    instance.sanitize();
+    assert_eq!(instance.phone_country, None);
+    assert_eq!(instance.national, None);
+    assert_eq!(instance.national_country, None);
+    assert_eq!(instance.format_country, None);
+}
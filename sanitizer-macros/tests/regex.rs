@@ -0,0 +1,40 @@
+use sanitizer::prelude::*;
+
+#[derive(Sanitizer)]
+struct RegexTest {
+    #[sanitizer(regex_replace(r"\s+", " "))]
+    replace: String,
+    #[sanitizer(regex_replace(r"(\d{4})-(\d{2})-(\d{2})", "$3.$2.$1"))]
+    replace_groups: String,
+    #[sanitizer(regex_keep("[0-9]"))]
+    keep: String,
+    #[sanitizer(regex_extract(r"ORD-(\d+)", 1))]
+    extract: String,
+    #[sanitizer(trim, regex_extract("[a-z]+@[a-z.]+"))]
+    extract_match: Option<String>,
+}
+
+#[test]
+fn regex_sanitizers() {
+    let mut instance = RegexTest {
+        replace: String::from("Hello \t\n World"),
+        replace_groups: String::from("2024-01-31"),
+        keep: String::from("+1 (555) 010"),
+        extract: String::from("Re: ORD-1234 shipped"),
+        extract_match: Some(String::from(" mail me at john@example.com ")),
+    };
+    instance.sanitize();
+    assert_eq!(instance.replace, "Hello World");
+    assert_eq!(instance.replace_groups, "31.01.2024");
+    assert_eq!(instance.keep, "1555010");
+    assert_eq!(instance.extract, "1234");
+    assert_eq!(
+        instance.extract_match,
+        Some(String::from("john@example.com"))
+    );
+
+    // the cached regexes are reused
+    instance.extract = String::from("no order");
+    instance.sanitize();
+    assert_eq!(instance.extract, "");
+}
@@ -4,6 +4,32 @@ use sanitizer::prelude::*;
 struct SanitizerTest {
     #[sanitizer(trim)]
     trim: String,
+    #[sanitizer(trim_start)]
+    trim_start: String,
+    #[sanitizer(trim_end)]
+    trim_end: String,
+    #[sanitizer(trim_matches("-_"))]
+    trim_matches: String,
+    #[sanitizer(collapse_whitespace, trim)]
+    collapse_whitespace: String,
+    #[sanitizer(normalize_whitespace)]
+    normalize_whitespace: String,
+    #[sanitizer(normalize_whitespace(keep_newlines = false))]
+    normalize_whitespace_flat: String,
+    #[sanitizer(normalize_newlines)]
+    normalize_newlines: String,
+    #[sanitizer(normalize_newlines(crlf))]
+    normalize_newlines_crlf: String,
+    #[sanitizer(strip_trailing_whitespace_per_line, max_consecutive_newlines(2))]
+    max_consecutive_newlines: String,
+    #[sanitizer(strip_control)]
+    strip_control: String,
+    #[sanitizer(strip_control(keep = "\n\t"))]
+    strip_control_keep: String,
+    #[sanitizer(strip_invisible)]
+    strip_invisible: String,
+    #[sanitizer(strip_bidi_controls)]
+    strip_bidi_controls: String,
     #[sanitizer(numeric)]
     numeric: String,
     #[sanitizer(alphanumeric)]
@@ -12,6 +38,67 @@ struct SanitizerTest {
     lower_case: String,
     #[sanitizer(upper_case)]
     upper_case: String,
+    #[sanitizer(lower_case(locale = "tr"))]
+    lower_case_locale: String,
+    #[sanitizer(upper_case(locale = "el"))]
+    upper_case_locale: String,
+    #[sanitizer(ascii_numeric)]
+    ascii_numeric: String,
+    #[sanitizer(keep(ascii_digit, "+"))]
+    keep: String,
+    #[sanitizer(keep(greek, space_separator, "a-c"))]
+    keep_script: String,
+    #[sanitizer(remove(punctuation))]
+    remove: String,
+    #[sanitizer(html_escape)]
+    html_escape: String,
+    #[sanitizer(html_unescape)]
+    html_unescape: String,
+    #[sanitizer(csv_safe)]
+    csv_safe: String,
+    #[sanitizer(csv_safe(quote, preserve_numbers))]
+    csv_safe_quote: String,
+    #[sanitizer(csv_safe(preserve_numbers))]
+    csv_safe_number: String,
+    #[sanitizer(log_safe)]
+    log_safe: String,
+    #[sanitizer(log_safe(8))]
+    log_safe_limit: String,
+    #[sanitizer(filename)]
+    filename: String,
+    #[sanitizer(filename(10))]
+    filename_limit: String,
+    #[sanitizer(relative_path)]
+    relative_path: String,
+    #[sanitizer(email)]
+    email: String,
+    #[sanitizer(email_canonical)]
+    email_canonical: String,
+    #[sanitizer(hostname, strip_www)]
+    hostname: String,
+    #[sanitizer(hostname_unicode)]
+    hostname_unicode: String,
+    #[sanitizer(url)]
+    url: String,
+    #[sanitizer(url(
+        default_scheme = "http",
+        strip_fragment,
+        sort_query,
+        remove_params = "ref"
+    ))]
+    url_options: String,
+    #[sanitizer(case_fold)]
+    case_fold: String,
+    #[sanitizer(to_ascii)]
+    to_ascii: String,
+    #[sanitizer(transliterate("?"))]
+    transliterate: String,
+    #[sanitizer(slugify)]
+    slugify: String,
+    #[sanitizer(slugify(sep = "_", max = 12))]
+    slugify_with: String,
+    #[sanitizer(trim, identifier_key)]
+    identifier_key: String,
     #[sanitizer(camel_case)]
     camel_case: String,
     #[sanitizer(snake_case)]
@@ -26,6 +113,32 @@ struct SanitizerTest {
     clamp_str: String,
     #[sanitizer(clamp(10, 50))]
     clamp_int: u8,
+    #[sanitizer(clamp(2))]
+    clamp_bytes: String,
+    #[sanitizer(clamp(4, unit = graphemes))]
+    clamp_graphemes: String,
+    #[sanitizer(clamp(6, unit = chars, ellipsis = "…"))]
+    clamp_ellipsis: String,
+    #[sanitizer(truncate_words(16, "…"))]
+    truncate_words: String,
+    #[sanitizer(title_case)]
+    title_case: String,
+    #[sanitizer(pascal_case("HTTP", "ID"))]
+    pascal_case: String,
+    #[sanitizer(train_case)]
+    train_case: String,
+    #[sanitizer(sentence_case("ID"))]
+    sentence_case: String,
+    #[sanitizer(lower_case_words)]
+    lower_case_words: String,
+    #[sanitizer(person_name)]
+    person_name: String,
+    #[sanitizer(person_name("DeVito"))]
+    person_name_exceptions: String,
+    #[sanitizer(nfc)]
+    nfc: String,
+    #[sanitizer(nfkd)]
+    nfkd: String,
     #[sanitizer(e164)]
     phone_number: String,
     #[sanitizer(trim, screaming_snake_case)]
@@ -36,10 +149,51 @@ struct SanitizerTest {
 fn sanitizer_check() {
     let mut instance = SanitizerTest {
         trim: String::from("    test     "),
+        trim_start: String::from("  test  "),
+        trim_end: String::from("  test  "),
+        trim_matches: String::from("--_test_-"),
+        collapse_whitespace: String::from(" Hello \u{a0}\t World\u{3000}"),
+        normalize_whitespace: String::from("Hello \r\n\r\n  World"),
+        normalize_whitespace_flat: String::from("Hello \r\n\r\n  World"),
+        normalize_newlines: String::from("Hello\r\nWorld\r"),
+        normalize_newlines_crlf: String::from("Hello\nWorld\u{2028}"),
+        max_consecutive_newlines: String::from("Hello \n\n \n\nWorld"),
+        strip_control: String::from("Hello\u{0}\tWorld\n"),
+        strip_control_keep: String::from("Hello\u{0}\tWorld\r\n"),
+        strip_invisible: String::from("Hello\u{200b}World\u{feff}"),
+        strip_bidi_controls: String::from("Hello\u{202e}World"),
         numeric: String::from("HelloWorld8130"),
         alphanumeric: String::from("Hello,藏World&&"),
         lower_case: String::from("HELLO, WORLD"),
         upper_case: String::from("hello, world"),
+        lower_case_locale: String::from("DİYARBAKIR"),
+        upper_case_locale: String::from("Οδυσσεύς"),
+        ascii_numeric: String::from("12\u{663}3"),
+        keep: String::from("+44 (0) 20\u{663}"),
+        keep_script: String::from("Αθήνα abcd"),
+        remove: String::from("Hello, World!"),
+        html_escape: String::from("<b>\"Tom\" & 'Jerry'</b>"),
+        html_unescape: String::from("Tom &amp;amp; Jerry&#33;"),
+        csv_safe: String::from("=1+2"),
+        csv_safe_quote: String::from("@SUM(\"A1\")"),
+        csv_safe_number: String::from("+44 20 7946 0018"),
+        log_safe: String::from("admin\n\u{1B}[32mINFO\u{1B}[0m ok"),
+        log_safe_limit: String::from("Hello\r\nWorld"),
+        filename: String::from("../../CON.txt"),
+        filename_limit: String::from("holiday photo.jpeg"),
+        relative_path: String::from("/uploads/./a/../../../etc/passwd"),
+        email: String::from(" John@München.DE "),
+        email_canonical: String::from("John.Doe+news@googlemail.com"),
+        hostname: String::from(" WWW.München.DE. "),
+        hostname_unicode: String::from("XN--MNCHEN-3YA.de"),
+        url: String::from("Example.COM:443/a/../b?utm_source=news&id=1"),
+        url_options: String::from("example.com/?b=2&ref=x&a=1#top"),
+        case_fold: String::from("STRASSE Straße"),
+        to_ascii: String::from("Crème Brûlée"),
+        transliterate: String::from("Škoda\u{E000}"),
+        slugify: String::from("Crème Brûlée: A Recipe!"),
+        slugify_with: String::from("Hello big world"),
+        identifier_key: String::from(" ＪＯＨＮ\u{200B}Doe "),
         camel_case: String::from("hello_world"),
         snake_case: String::from("helloWorld"),
         screaming_kebab_case: String::from("helloWorld"),
@@ -47,15 +201,72 @@ fn sanitizer_check() {
         screaming_snake_case: String::from("hello, world"),
         clamp_str: String::from("Hello, World"),
         clamp_int: 9,
+        clamp_bytes: String::from("héllo"),
+        clamp_graphemes: String::from("🇬🇧🇫🇷🇩🇪🇮🇹🇪🇸"),
+        clamp_ellipsis: String::from("Hello, World"),
+        truncate_words: String::from("The quick brown fox"),
+        title_case: String::from("hello_world"),
+        pascal_case: String::from("http_request_id"),
+        train_case: String::from("helloWorld"),
+        sentence_case: String::from("USER_ID_VALUE"),
+        lower_case_words: String::from("HelloWorld"),
+        person_name: String::from("  jean-luc o'brien "),
+        person_name_exceptions: String::from("DANNY DEVITO"),
+        nfc: String::from("Cafe\u{301}"),
+        nfkd: String::from("\u{fb01}anc\u{e9}"),
         phone_number: String::from("+1 (454)"),
         multiple_sanitizers: String::from("    helloWorld123  "),
     };
     instance.sanitize();
     assert_eq!(instance.trim, "test");
+    assert_eq!(instance.trim_start, "test  ");
+    assert_eq!(instance.trim_end, "  test");
+    assert_eq!(instance.trim_matches, "test");
+    assert_eq!(instance.collapse_whitespace, "Hello World");
+    assert_eq!(instance.normalize_whitespace, "Hello\n\nWorld");
+    assert_eq!(instance.normalize_whitespace_flat, "Hello World");
+    assert_eq!(instance.normalize_newlines, "Hello\nWorld\n");
+    assert_eq!(instance.normalize_newlines_crlf, "Hello\r\nWorld\r\n");
+    assert_eq!(instance.max_consecutive_newlines, "Hello\n\nWorld");
+    assert_eq!(instance.strip_control, "HelloWorld");
+    assert_eq!(instance.strip_control_keep, "Hello\tWorld\n");
+    assert_eq!(instance.strip_invisible, "HelloWorld");
+    assert_eq!(instance.strip_bidi_controls, "HelloWorld");
     assert_eq!(instance.numeric, "8130");
     assert_eq!(instance.alphanumeric, "Hello藏World");
     assert_eq!(instance.lower_case, "hello, world");
     assert_eq!(instance.upper_case, "HELLO, WORLD");
+    assert_eq!(instance.lower_case_locale, "diyarbakır");
+    assert_eq!(instance.upper_case_locale, "ΟΔΥΣΣΕΥΣ");
+    assert_eq!(instance.ascii_numeric, "123");
+    assert_eq!(instance.keep, "+44020");
+    assert_eq!(instance.keep_script, "Αθήνα abc");
+    assert_eq!(instance.remove, "Hello World");
+    assert_eq!(
+        instance.html_escape,
+        "&lt;b&gt;&quot;Tom&quot; &amp; &#x27;Jerry&#x27;&lt;/b&gt;"
+    );
+    assert_eq!(instance.html_unescape, "Tom & Jerry!");
+    assert_eq!(instance.csv_safe, "'=1+2");
+    assert_eq!(instance.csv_safe_quote, "\"'@SUM(\"\"A1\"\")\"");
+    assert_eq!(instance.csv_safe_number, "+44 20 7946 0018");
+    assert_eq!(instance.log_safe, "admin\\nINFO ok");
+    assert_eq!(instance.log_safe_limit, "Hello\\r…");
+    assert_eq!(instance.filename, "_CON.txt");
+    assert_eq!(instance.filename_limit, "holid.jpeg");
+    assert_eq!(instance.relative_path, "etc/passwd");
+    assert_eq!(instance.email, "John@xn--mnchen-3ya.de");
+    assert_eq!(instance.email_canonical, "johndoe@gmail.com");
+    assert_eq!(instance.hostname, "xn--mnchen-3ya.de");
+    assert_eq!(instance.hostname_unicode, "münchen.de");
+    assert_eq!(instance.url, "https://example.com/b?id=1");
+    assert_eq!(instance.url_options, "http://example.com/?a=1&b=2");
+    assert_eq!(instance.case_fold, "strasse strasse");
+    assert_eq!(instance.to_ascii, "Creme Brulee");
+    assert_eq!(instance.transliterate, "Skoda?");
+    assert_eq!(instance.slugify, "creme-brulee-a-recipe");
+    assert_eq!(instance.slugify_with, "hello_big");
+    assert_eq!(instance.identifier_key, "johndoe");
     assert_eq!(instance.camel_case, "helloWorld");
     assert_eq!(instance.snake_case, "hello_world");
     assert_eq!(instance.screaming_snake_case, "HELLO_WORLD");
@@ -64,6 +275,51 @@ fn sanitizer_check() {
     assert_eq!(instance.screaming_kebab_case, "HELLO-WORLD");
     assert_eq!(instance.clamp_str, "Hello, Wor");
     assert_eq!(instance.clamp_int, 10);
+    assert_eq!(instance.clamp_bytes, "h");
+    assert_eq!(instance.clamp_graphemes, "🇬🇧🇫🇷🇩🇪🇮🇹");
+    assert_eq!(instance.clamp_ellipsis, "Hello…");
+    assert_eq!(instance.truncate_words, "The quick brown…");
+    assert_eq!(instance.title_case, "Hello World");
+    assert_eq!(instance.pascal_case, "HTTPRequestID");
+    assert_eq!(instance.train_case, "Hello-World");
+    assert_eq!(instance.sentence_case, "User ID value");
+    assert_eq!(instance.lower_case_words, "hello world");
+    assert_eq!(instance.person_name, "Jean-Luc O'Brien");
+    assert_eq!(instance.person_name_exceptions, "Danny DeVito");
+    assert_eq!(instance.nfc, "Caf\u{e9}");
+    assert_eq!(instance.nfkd, "fiance\u{301}");
     assert_eq!(instance.phone_number, "+1454");
     assert_eq!(instance.multiple_sanitizers, "HELLO_WORLD123");
 }
+
+#[derive(Sanitizer)]
+struct LocaleTest {
+    language: String,
+    #[sanitizer(lower_case(locale_from = "language"))]
+    city: String,
+    locale: sanitizer::Locale,
+    #[sanitizer(upper_case(locale_from = "locale"))]
+    name: Option<String>,
+}
+
+#[test]
+fn locale_from_field() {
+    let mut instance = LocaleTest {
+        language: String::from("tr-TR"),
+        city: String::from("DİYARBAKIR"),
+        locale: sanitizer::Locale::Greek,
+        name: Some(String::from("Οδυσσεύς")),
+    };
+    instance.sanitize();
+    assert_eq!(instance.city, "diyarbakır");
+    assert_eq!(instance.name, Some(String::from("ΟΔΥΣΣΕΥΣ")));
+
+    let mut instance = LocaleTest {
+        language: String::from("en"),
+        city: String::from("DİYARBAKIR"),
+        locale: sanitizer::Locale::Root,
+        name: None,
+    };
+    instance.sanitize();
+    assert_eq!(instance.city, "di\u{307}yarbakir");
+}
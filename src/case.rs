@@ -0,0 +1,90 @@
+use heck::ToSnakeCase;
+
+/// Word based case styles, used by
+/// [`StringSanitizer::to_case_with_acronyms`](crate::StringSanitizer::to_case_with_acronyms)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WordCase {
+    /// Title Case
+    Title,
+    /// PascalCase, also known as UpperCamelCase
+    Pascal,
+    /// Train-Case
+    Train,
+    /// Sentence case
+    Sentence,
+    /// lower case words
+    LowerWords,
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+// split the content into words the same way heck does, and join them in
+// the given case. Words matching an acronym (ignoring case) are replaced
+// by the acronym
+pub(crate) fn to_case(content: &str, case: WordCase, acronyms: &[&str]) -> String {
+    let snake = content.to_snake_case();
+    let words = snake.split('_').filter(|word| !word.is_empty());
+    let cased: Vec<String> = words
+        .enumerate()
+        .map(|(index, word)| {
+            if let Some(acronym) = acronyms
+                .iter()
+                .find(|acronym| acronym.to_lowercase() == word)
+            {
+                return acronym.to_string();
+            }
+            match case {
+                WordCase::Title | WordCase::Pascal | WordCase::Train => capitalize(word),
+                WordCase::Sentence if index == 0 => capitalize(word),
+                WordCase::Sentence | WordCase::LowerWords => word.to_owned(),
+            }
+        })
+        .collect();
+    let separator = match case {
+        WordCase::Pascal => "",
+        WordCase::Train => "-",
+        _ => " ",
+    };
+    cased.join(separator)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn acronyms() {
+        let acronyms = ["HTTP", "ID"];
+        let content = "http_request_id";
+        assert_eq!(
+            "HTTP Request ID",
+            to_case(content, WordCase::Title, &acronyms)
+        );
+        assert_eq!(
+            "HTTPRequestID",
+            to_case(content, WordCase::Pascal, &acronyms)
+        );
+        assert_eq!(
+            "HTTP-Request-ID",
+            to_case(content, WordCase::Train, &acronyms)
+        );
+        assert_eq!(
+            "HTTP request ID",
+            to_case(content, WordCase::Sentence, &acronyms)
+        );
+        assert_eq!(
+            "HTTP request ID",
+            to_case(content, WordCase::LowerWords, &acronyms)
+        );
+        assert_eq!(
+            "Http request id",
+            to_case("HTTPRequestID", WordCase::Sentence, &[])
+        );
+    }
+}
@@ -0,0 +1,230 @@
+use unicode_general_category::{GeneralCategory, get_general_category};
+use unicode_script::{Script, UnicodeScript};
+
+/// Class of characters matched by
+/// [`StringSanitizer::keep`](crate::StringSanitizer::keep) and
+/// [`StringSanitizer::remove`](crate::StringSanitizer::remove)
+///
+/// # Example
+///
+/// ```
+/// use sanitizer::{CharClass, Script, StringSanitizer};
+///
+/// let mut sanitizer = StringSanitizer::from("+1 (555) ٣٣ Αθήνα");
+/// sanitizer.keep(&[CharClass::AsciiDigit, CharClass::from("+")]);
+/// assert_eq!(sanitizer.get(), "+1555");
+///
+/// let mut sanitizer = StringSanitizer::from("Αθήνα Athens");
+/// sanitizer.remove(&[CharClass::Script(Script::Latin)]);
+/// assert_eq!(sanitizer.get(), "Αθήνα ");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CharClass {
+    /// Any ASCII character
+    Ascii,
+    /// `0-9`
+    AsciiDigit,
+    /// `a-z` and `A-Z`
+    AsciiAlpha,
+    /// `0-9`, `a-z` and `A-Z`
+    AsciiAlnum,
+    /// `A-Z`
+    AsciiUppercase,
+    /// `a-z`
+    AsciiLowercase,
+    /// ASCII punctuation and symbols, eg. `!` or `~`
+    AsciiPunctuation,
+    /// Space, tab, line feed, form feed and carriage return
+    AsciiWhitespace,
+    /// Any letter (general category L)
+    Letter,
+    /// Any combining mark (general category M)
+    Mark,
+    /// Any number, including non ASCII digits like "٣" (general category N)
+    Number,
+    /// Any punctuation (general category P)
+    Punctuation,
+    /// Any symbol, eg. currency or math symbols (general category S)
+    Symbol,
+    /// Any space, line or paragraph separator (general category Z)
+    Separator,
+    /// Control, format, private use, surrogate and unassigned characters
+    /// (general category C)
+    Other,
+    /// Characters with the Unicode White_Space property
+    Whitespace,
+    /// A single Unicode general category
+    Category(GeneralCategory),
+    /// Characters of a script, eg. `Script::Latin`
+    Script(Script),
+    /// Explicit characters, `a-z` is the range from a to z. A `-` at the
+    /// start or end of the set stands for itself
+    Set(String),
+}
+
+impl From<&str> for CharClass {
+    fn from(set: &str) -> Self {
+        Self::Set(set.to_owned())
+    }
+}
+
+impl CharClass {
+    /// Whether the character belongs to the class
+    pub fn matches(&self, character: char) -> bool {
+        match self {
+            Self::Ascii => character.is_ascii(),
+            Self::AsciiDigit => character.is_ascii_digit(),
+            Self::AsciiAlpha => character.is_ascii_alphabetic(),
+            Self::AsciiAlnum => character.is_ascii_alphanumeric(),
+            Self::AsciiUppercase => character.is_ascii_uppercase(),
+            Self::AsciiLowercase => character.is_ascii_lowercase(),
+            Self::AsciiPunctuation => character.is_ascii_punctuation(),
+            Self::AsciiWhitespace => character.is_ascii_whitespace(),
+            Self::Letter => is_letter(get_general_category(character)),
+            Self::Mark => is_mark(get_general_category(character)),
+            Self::Number => is_number(get_general_category(character)),
+            Self::Punctuation => is_punctuation(get_general_category(character)),
+            Self::Symbol => is_symbol(get_general_category(character)),
+            Self::Separator => is_separator(get_general_category(character)),
+            Self::Other => is_other(get_general_category(character)),
+            Self::Whitespace => character.is_whitespace(),
+            Self::Category(category) => get_general_category(character) == *category,
+            Self::Script(script) => character.script() == *script,
+            Self::Set(set) => set_contains(set, character),
+        }
+    }
+}
+
+fn is_letter(category: GeneralCategory) -> bool {
+    matches!(
+        category,
+        GeneralCategory::UppercaseLetter
+            | GeneralCategory::LowercaseLetter
+            | GeneralCategory::TitlecaseLetter
+            | GeneralCategory::ModifierLetter
+            | GeneralCategory::OtherLetter
+    )
+}
+
+fn is_mark(category: GeneralCategory) -> bool {
+    matches!(
+        category,
+        GeneralCategory::NonspacingMark
+            | GeneralCategory::SpacingMark
+            | GeneralCategory::EnclosingMark
+    )
+}
+
+fn is_number(category: GeneralCategory) -> bool {
+    matches!(
+        category,
+        GeneralCategory::DecimalNumber
+            | GeneralCategory::LetterNumber
+            | GeneralCategory::OtherNumber
+    )
+}
+
+fn is_punctuation(category: GeneralCategory) -> bool {
+    matches!(
+        category,
+        GeneralCategory::ConnectorPunctuation
+            | GeneralCategory::DashPunctuation
+            | GeneralCategory::OpenPunctuation
+            | GeneralCategory::ClosePunctuation
+            | GeneralCategory::InitialPunctuation
+            | GeneralCategory::FinalPunctuation
+            | GeneralCategory::OtherPunctuation
+    )
+}
+
+fn is_symbol(category: GeneralCategory) -> bool {
+    matches!(
+        category,
+        GeneralCategory::MathSymbol
+            | GeneralCategory::CurrencySymbol
+            | GeneralCategory::ModifierSymbol
+            | GeneralCategory::OtherSymbol
+    )
+}
+
+fn is_separator(category: GeneralCategory) -> bool {
+    matches!(
+        category,
+        GeneralCategory::SpaceSeparator
+            | GeneralCategory::LineSeparator
+            | GeneralCategory::ParagraphSeparator
+    )
+}
+
+fn is_other(category: GeneralCategory) -> bool {
+    matches!(
+        category,
+        GeneralCategory::Control
+            | GeneralCategory::Format
+            | GeneralCategory::PrivateUse
+            | GeneralCategory::Surrogate
+            | GeneralCategory::Unassigned
+    )
+}
+
+// whether the char is in a set like "a-z_", where x-y is a range
+fn set_contains(set: &str, character: char) -> bool {
+    let chars: Vec<char> = set.chars().collect();
+    let mut index = 0;
+    while index < chars.len() {
+        if index + 2 < chars.len() && chars[index + 1] == '-' {
+            if (chars[index]..=chars[index + 2]).contains(&character) {
+                return true;
+            }
+            index += 3;
+        } else {
+            if chars[index] == character {
+                return true;
+            }
+            index += 1;
+        }
+    }
+    false
+}
+
+// keep or remove the chars matching any of the classes
+pub(crate) fn filter(content: &str, classes: &[CharClass], keep: bool) -> String {
+    content
+        .chars()
+        .filter(|character| classes.iter().any(|class| class.matches(*character)) == keep)
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn sets() {
+        assert!(set_contains("a-z_", 'q'));
+        assert!(set_contains("a-z_", '_'));
+        assert!(!set_contains("a-z_", '-'));
+        assert!(set_contains("-a", '-'));
+        assert!(set_contains("a-", '-'));
+        assert!(!set_contains("", 'a'));
+    }
+
+    #[test]
+    fn categories() {
+        assert!(CharClass::Number.matches('\u{663}'));
+        assert!(!CharClass::AsciiDigit.matches('\u{663}'));
+        assert!(CharClass::Punctuation.matches('¿'));
+        assert!(CharClass::Category(GeneralCategory::CurrencySymbol).matches('€'));
+        assert!(CharClass::Script(Script::Cyrillic).matches('ж'));
+    }
+
+    #[test]
+    fn keep_and_remove() {
+        let classes = [CharClass::AsciiDigit, CharClass::from("+")];
+        assert_eq!("+4412", filter("+44 (1) 2\u{663}", &classes, true));
+        assert_eq!(
+            "Hello World",
+            filter("Hello, World!", &[CharClass::Punctuation], false)
+        );
+    }
+}
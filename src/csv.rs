@@ -0,0 +1,87 @@
+// characters which make spreadsheets read a cell as a formula
+static FORMULA_TRIGGERS: [char; 6] = ['=', '+', '-', '@', '\t', '\r'];
+
+/// How [`StringSanitizer::csv_safe_with`](crate::StringSanitizer::csv_safe_with)
+/// neutralises values which would be read as formulas
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CsvSafeMode {
+    /// Prefix the value with `'`, for CSV writers which quote fields
+    /// themselves
+    #[default]
+    Prefix,
+    /// Prefix the value with `'` and output a complete quoted CSV field:
+    /// every value is wrapped in double quotes and double quotes inside it
+    /// are doubled, for CSV files written by hand
+    Quote,
+}
+
+/// Options of [`StringSanitizer::csv_safe_with`](crate::StringSanitizer::csv_safe_with)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CsvSafeOptions {
+    /// How formulas are neutralised
+    pub mode: CsvSafeMode,
+    /// Leave numbers like "-5" or "+44 20 7946 0018" unchanged, they are
+    /// not executed as formulas
+    pub preserve_numbers: bool,
+}
+
+// a sign followed by digits, spaces and separators
+fn is_number(content: &str) -> bool {
+    let digits = content.strip_prefix(['+', '-']).unwrap_or(content);
+    digits.chars().any(|character| character.is_ascii_digit())
+        && digits
+            .chars()
+            .all(|character| character.is_ascii_digit() || matches!(character, ' ' | '.' | ','))
+}
+
+// see StringSanitizer::csv_safe_with
+pub(crate) fn csv_safe(content: &str, options: CsvSafeOptions) -> String {
+    let is_formula =
+        content.starts_with(FORMULA_TRIGGERS) && !(options.preserve_numbers && is_number(content));
+    let prefix = if is_formula { "'" } else { "" };
+    match options.mode {
+        CsvSafeMode::Prefix => format!("{}{}", prefix, content),
+        CsvSafeMode::Quote => format!("\"{}{}\"", prefix, content.replace('"', "\"\"")),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn prefix() {
+        let options = CsvSafeOptions::default();
+        assert_eq!("'=SUM(A1:A9)", csv_safe("=SUM(A1:A9)", options));
+        assert_eq!("'@cmd", csv_safe("@cmd", options));
+        assert_eq!("'\tx", csv_safe("\tx", options));
+        assert_eq!("'-5", csv_safe("-5", options));
+        assert_eq!("a=b", csv_safe("a=b", options));
+    }
+
+    #[test]
+    fn quote() {
+        let options = CsvSafeOptions {
+            mode: CsvSafeMode::Quote,
+            ..Default::default()
+        };
+        assert_eq!(
+            "\"'=HYPERLINK(\"\"http://x\"\")\"",
+            csv_safe("=HYPERLINK(\"http://x\")", options)
+        );
+        assert_eq!("\"Hello, World\"", csv_safe("Hello, World", options));
+    }
+
+    #[test]
+    fn numbers() {
+        let options = CsvSafeOptions {
+            preserve_numbers: true,
+            ..Default::default()
+        };
+        assert_eq!("-5", csv_safe("-5", options));
+        assert_eq!("+44 20 7946 0018", csv_safe("+44 20 7946 0018", options));
+        assert_eq!("-1,234.5", csv_safe("-1,234.5", options));
+        assert_eq!("'-1+2", csv_safe("-1+2", options));
+        assert_eq!("'-", csv_safe("-", options));
+    }
+}
@@ -0,0 +1,172 @@
+use crate::Error;
+use idna::domain_to_ascii_strict;
+
+// longest local part and address allowed by RFC 5321
+const MAX_LOCAL_PART_LEN: usize = 64;
+const MAX_EMAIL_LEN: usize = 254;
+
+// characters which are only allowed in quoted local parts, RFC 5322
+static SPECIALS: [char; 12] = ['(', ')', '<', '>', '[', ']', ':', ';', '@', '\\', ',', '"'];
+
+// how a provider delivers different spellings of an address to the same
+// mailbox
+struct Provider {
+    domains: &'static [&'static str],
+    // the domain all aliases are canonicalized to
+    canonical_domain: Option<&'static str>,
+    // separator starting a tag which is ignored, eg. `+` in `john+news`
+    tag_separator: char,
+    ignores_dots: bool,
+}
+
+static PROVIDERS: [Provider; 3] = [
+    Provider {
+        domains: &["gmail.com", "googlemail.com"],
+        canonical_domain: Some("gmail.com"),
+        tag_separator: '+',
+        ignores_dots: true,
+    },
+    Provider {
+        domains: &[
+            "outlook.com",
+            "hotmail.com",
+            "live.com",
+            "icloud.com",
+            "me.com",
+            "mac.com",
+            "fastmail.com",
+            "protonmail.com",
+            "proton.me",
+        ],
+        canonical_domain: None,
+        tag_separator: '+',
+        ignores_dots: false,
+    },
+    Provider {
+        domains: &["yahoo.com"],
+        canonical_domain: None,
+        tag_separator: '-',
+        ignores_dots: false,
+    },
+];
+
+// an unquoted local part is dot separated atoms, so it cannot contain
+// specials such as `@` or start, end or repeat dots. A quoted string like
+// "a@b" may
+fn is_valid_local_part(local: &str) -> bool {
+    let quoted = local.len() >= 2 && local.starts_with('"') && local.ends_with('"');
+    let valid_atoms =
+        quoted || (!local.contains(SPECIALS) && local.split('.').all(|atom| !atom.is_empty()));
+    valid_atoms
+        && local.len() <= MAX_LOCAL_PART_LEN
+        && !local
+            .chars()
+            .any(|character| character.is_whitespace() || character.is_control())
+}
+
+// see StringSanitizer::try_email
+pub(crate) fn email(content: &str) -> Result<String, Error> {
+    let content = content.trim();
+    let (local, domain) = content.rsplit_once('@').ok_or(Error::InvalidEmail)?;
+    if !is_valid_local_part(local) {
+        return Err(Error::InvalidEmail);
+    }
+    let domain = domain.strip_suffix('.').unwrap_or(domain);
+    let domain = domain_to_ascii_strict(domain).map_err(|_| Error::InvalidEmail)?;
+    if domain.is_empty() {
+        return Err(Error::InvalidEmail);
+    }
+    let email = format!("{}@{}", local, domain);
+    if email.len() > MAX_EMAIL_LEN {
+        return Err(Error::InvalidEmail);
+    }
+    Ok(email)
+}
+
+// see StringSanitizer::try_email_canonical
+pub(crate) fn email_canonical(content: &str) -> Result<String, Error> {
+    let email = email(content)?;
+    let (local, domain) = email.rsplit_once('@').ok_or(Error::InvalidEmail)?;
+    let mut local = local.to_lowercase();
+    let mut domain = domain;
+    let provider = PROVIDERS
+        .iter()
+        .find(|provider| provider.domains.contains(&domain));
+    if let Some(provider) = provider {
+        if let Some(tag) = local.find(provider.tag_separator) {
+            local.truncate(tag);
+        }
+        if provider.ignores_dots {
+            local.retain(|character| character != '.');
+        }
+        domain = provider.canonical_domain.unwrap_or(domain);
+    }
+    if local.is_empty() {
+        return Err(Error::InvalidEmail);
+    }
+    Ok(format!("{}@{}", local, domain))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn normalize() {
+        assert_eq!(
+            Ok(String::from("John.Doe@example.com")),
+            email("  John.Doe@EXAMPLE.com. ")
+        );
+        assert_eq!(
+            Ok(String::from("info@xn--mnchen-3ya.de")),
+            email("info@München.de")
+        );
+        assert_eq!(
+            Ok(String::from("\"a@b\"@example.com")),
+            email("\"a@b\"@example.com")
+        );
+        assert_eq!(
+            Ok(String::from("\".a..b\"@example.com")),
+            email("\".a..b\"@example.com")
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(Err(Error::InvalidEmail), email("john.example.com"));
+        assert_eq!(Err(Error::InvalidEmail), email("@example.com"));
+        assert_eq!(Err(Error::InvalidEmail), email("john@"));
+        assert_eq!(Err(Error::InvalidEmail), email("jo hn@example.com"));
+        assert_eq!(Err(Error::InvalidEmail), email("john@exa mple.com"));
+        assert_eq!(Err(Error::InvalidEmail), email("john@example..com"));
+        assert_eq!(Err(Error::InvalidEmail), email("a@b@c.com"));
+        assert_eq!(Err(Error::InvalidEmail), email(".a@example.com"));
+        assert_eq!(Err(Error::InvalidEmail), email("a.@example.com"));
+        assert_eq!(Err(Error::InvalidEmail), email("a..b@example.com"));
+        assert_eq!(Err(Error::InvalidEmail), email("\"@example.com"));
+        assert_eq!(Err(Error::InvalidEmail), email("a,b@example.com"));
+        let long = format!("{}@example.com", "a".repeat(65));
+        assert_eq!(Err(Error::InvalidEmail), email(&long));
+    }
+
+    #[test]
+    fn canonical() {
+        assert_eq!(
+            Ok(String::from("johndoe@gmail.com")),
+            email_canonical("John.Doe+news@GoogleMail.com")
+        );
+        assert_eq!(
+            Ok(String::from("john@outlook.com")),
+            email_canonical("John+shop@Outlook.com")
+        );
+        assert_eq!(
+            Ok(String::from("john@yahoo.com")),
+            email_canonical("john-shop@yahoo.com")
+        );
+        assert_eq!(
+            Ok(String::from("john.doe+x@example.com")),
+            email_canonical("John.Doe+x@example.com")
+        );
+        assert_eq!(Err(Error::InvalidEmail), email_canonical("+news@gmail.com"));
+    }
+}
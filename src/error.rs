@@ -0,0 +1,38 @@
+use std::error;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+/// Error returned by the fallible (`try_*`) sanitizers when the input
+/// cannot be brought into the requested shape.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// The input could not be parsed as a phone number
+    InvalidPhoneNumber,
+    /// The input looks like a phone number but is not assigned to any
+    /// known number range
+    UnassignedPhoneNumber,
+    /// The path leaves the directory it is relative to through `..`
+    PathEscapesRoot,
+    /// The input is not a valid email address
+    InvalidEmail,
+    /// The input is not a valid http or https URL
+    InvalidUrl,
+    /// The input is not a valid hostname
+    InvalidHostname,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        let case = match self {
+            Self::InvalidPhoneNumber => "Invalid phone number",
+            Self::UnassignedPhoneNumber => "Phone number is not assigned",
+            Self::PathEscapesRoot => "Path escapes the root directory",
+            Self::InvalidEmail => "Invalid email address",
+            Self::InvalidUrl => "Invalid URL",
+            Self::InvalidHostname => "Invalid hostname",
+        };
+        write!(f, "{}", case)
+    }
+}
+
+impl error::Error for Error {}
@@ -0,0 +1,92 @@
+use crate::Error;
+use idna::{domain_to_ascii_strict, domain_to_unicode};
+
+// longest label and name allowed by RFC 1035, in their ASCII form
+const MAX_LABEL_LEN: usize = 63;
+const MAX_HOSTNAME_LEN: usize = 253;
+
+// see StringSanitizer::try_hostname
+pub(crate) fn hostname(content: &str) -> Result<String, Error> {
+    let content = content.trim();
+    let content = content.strip_suffix('.').unwrap_or(content);
+    let hostname = domain_to_ascii_strict(content).map_err(|_| Error::InvalidHostname)?;
+    let valid = !hostname.is_empty()
+        && hostname.len() <= MAX_HOSTNAME_LEN
+        && hostname
+            .split('.')
+            .all(|label| !label.is_empty() && label.len() <= MAX_LABEL_LEN);
+    if !valid {
+        return Err(Error::InvalidHostname);
+    }
+    Ok(hostname)
+}
+
+// see StringSanitizer::try_hostname_unicode
+pub(crate) fn hostname_unicode(content: &str) -> Result<String, Error> {
+    let hostname = hostname(content)?;
+    match domain_to_unicode(&hostname) {
+        (unicode, Ok(())) => Ok(unicode),
+        (_, Err(_)) => Err(Error::InvalidHostname),
+    }
+}
+
+// see StringSanitizer::strip_www, which keeps at least two labels so that
+// `www.com` is not turned into a top level domain
+pub(crate) fn strip_www(content: &str) -> &str {
+    match content.get(..4) {
+        Some(prefix) if prefix.eq_ignore_ascii_case("www.") && content[4..].contains('.') => {
+            &content[4..]
+        }
+        _ => content,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn normalize() {
+        assert_eq!(Ok(String::from("example.com")), hostname(" Example.COM. "));
+        assert_eq!(
+            Ok(String::from("xn--mnchen-3ya.de")),
+            hostname("München.DE")
+        );
+        assert_eq!(Ok(String::from("localhost")), hostname("localhost"));
+    }
+
+    #[test]
+    fn unicode() {
+        assert_eq!(
+            Ok(String::from("münchen.de")),
+            hostname_unicode("xn--mnchen-3ya.de")
+        );
+        assert_eq!(
+            Ok(String::from("münchen.de")),
+            hostname_unicode("MÜNCHEN.de.")
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(Err(Error::InvalidHostname), hostname(""));
+        assert_eq!(Err(Error::InvalidHostname), hostname("."));
+        assert_eq!(Err(Error::InvalidHostname), hostname("exa mple.com"));
+        assert_eq!(Err(Error::InvalidHostname), hostname("example..com"));
+        assert_eq!(Err(Error::InvalidHostname), hostname("-example.com"));
+        assert_eq!(Err(Error::InvalidHostname), hostname("example.com/path"));
+        let long_label = format!("{}.com", "a".repeat(64));
+        assert_eq!(Err(Error::InvalidHostname), hostname(&long_label));
+        let long_name = format!("{}com", "a.".repeat(127));
+        assert_eq!(Err(Error::InvalidHostname), hostname(&long_name));
+    }
+
+    #[test]
+    fn www() {
+        assert_eq!("example.com", strip_www("www.example.com"));
+        assert_eq!("example.com", strip_www("WWW.example.com"));
+        assert_eq!("www2.example.com", strip_www("www2.example.com"));
+        assert_eq!("www.", strip_www("www."));
+        assert_eq!("www.com", strip_www("www.com"));
+    }
+}
@@ -0,0 +1,117 @@
+use ammonia::Builder;
+use std::collections::{HashMap, HashSet};
+use std::sync::OnceLock;
+
+// inline formatting, links, paragraphs and lists
+static BASIC_TAGS: [&str; 19] = [
+    "a",
+    "b",
+    "blockquote",
+    "br",
+    "code",
+    "del",
+    "em",
+    "i",
+    "ins",
+    "li",
+    "ol",
+    "p",
+    "pre",
+    "s",
+    "strong",
+    "sub",
+    "sup",
+    "u",
+    "ul",
+];
+
+/// Allowlist used by
+/// [`StringSanitizer::html_sanitize`](crate::StringSanitizer::html_sanitize).
+///
+/// With every policy comments and event handler attributes such as
+/// `onclick` are removed, `script` and `style` elements are dropped with
+/// their content, links only keep safe URL schemes (no `javascript:`) and
+/// get `rel="noopener noreferrer"`, and the output is well-formed HTML
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HtmlPolicy {
+    /// Remove all tags, keeping their (escaped) text
+    StripAll,
+    /// Allow inline formatting, links, paragraphs, code blocks and lists,
+    /// links may only use `http`, `https` and `mailto` URLs
+    BasicFormatting,
+    /// Allow the tags and attributes of common rich text, including
+    /// headings, images and tables
+    Relaxed,
+}
+
+impl HtmlPolicy {
+    // the builders are only configured once
+    fn builder(&self) -> &'static Builder<'static> {
+        static STRIP_ALL: OnceLock<Builder<'static>> = OnceLock::new();
+        static BASIC_FORMATTING: OnceLock<Builder<'static>> = OnceLock::new();
+        static RELAXED: OnceLock<Builder<'static>> = OnceLock::new();
+        match self {
+            Self::StripAll => STRIP_ALL.get_or_init(Builder::empty),
+            Self::BasicFormatting => BASIC_FORMATTING.get_or_init(|| {
+                let mut builder = Builder::default();
+                builder
+                    .tags(HashSet::from(BASIC_TAGS))
+                    .tag_attributes(HashMap::from([("a", HashSet::from(["href", "title"]))]))
+                    .generic_attributes(HashSet::new())
+                    .url_schemes(HashSet::from(["http", "https", "mailto"]));
+                builder
+            }),
+            Self::Relaxed => RELAXED.get_or_init(Builder::default),
+        }
+    }
+}
+
+// see StringSanitizer::html_sanitize
+pub(crate) fn html_sanitize(content: &str, policy: HtmlPolicy) -> String {
+    policy.builder().clean(content).to_string()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn strip_all() {
+        assert_eq!(
+            "Hello &lt;World&gt; &amp; you",
+            html_sanitize(
+                "<p>Hello <b>&lt;World&gt;</b> & you<script>alert(1)</script></p>",
+                HtmlPolicy::StripAll
+            )
+        );
+    }
+
+    #[test]
+    fn basic_formatting() {
+        assert_eq!(
+            "<p><b>Bold</b> <a rel=\"noopener noreferrer\">link</a> </p>",
+            html_sanitize(
+                "<p onclick=\"x()\"><b>Bold</b> <a href=\"javascript:x()\">link</a> <img src=\"a.png\"><!-- x --></p>",
+                HtmlPolicy::BasicFormatting
+            )
+        );
+        assert_eq!(
+            "<a href=\"https://example.com\" rel=\"noopener noreferrer\">x</a>",
+            html_sanitize(
+                "<a href=\"https://example.com\" style=\"color: red\">x",
+                HtmlPolicy::BasicFormatting
+            )
+        );
+    }
+
+    #[test]
+    fn relaxed() {
+        assert_eq!(
+            "<h1>Title</h1><img src=\"a.png\" alt=\"A\">",
+            html_sanitize(
+                "<h1>Title</h1><img src=\"a.png\" alt=\"A\" onerror=\"x()\">",
+                HtmlPolicy::Relaxed
+            )
+        );
+    }
+}
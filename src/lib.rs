@@ -36,8 +36,27 @@
 //! }
 //! ```
 //! To see a list of available sanitizers, check the [sanitizer-macros crate](https://docs.rs/sanitizer_macros/0.1.0/sanitizer_macros/derive.Sanitize.html)
+mod case;
+mod char_class;
+mod csv;
+mod email;
+mod error;
+mod hostname;
+#[cfg(feature = "html")]
+mod html;
 mod int_sanitizer;
+mod locale;
+mod log;
+mod markup;
+mod path;
+mod person_name;
+mod phone;
+mod slug;
 mod string_sanitizer;
+mod truncate;
+mod unicode;
+mod uri;
+mod whitespace;
 /// Bring all the sanitizers, the derive macro, and the Sanitizer trait in scope
 pub mod prelude {
     pub use crate::Sanitizer;
@@ -46,10 +65,48 @@ pub mod prelude {
     #[cfg(feature = "derive")]
     pub use sanitizer_macros::Sanitizer;
 }
+/// Word based case styles
+pub use crate::case::WordCase;
+/// Class of characters kept or removed by the character filters
+pub use crate::char_class::CharClass;
+/// Options for neutralising spreadsheet formulas in CSV values
+pub use crate::csv::{CsvSafeMode, CsvSafeOptions};
+/// Error returned by the fallible sanitizers
+pub use crate::error::Error;
+/// Allowlist used when sanitizing HTML
+#[cfg(feature = "html")]
+pub use crate::html::HtmlPolicy;
 /// Sanitizer methods for ints
 pub use crate::int_sanitizer::IntSanitizer;
+/// Language used for locale aware case conversion
+pub use crate::locale::Locale;
+/// Display wrapper which makes values safe for log lines
+pub use crate::log::{LOG_SAFE_LIMIT, LogSafe};
+/// Default byte limit of sanitized filenames
+pub use crate::path::FILENAME_LIMIT;
+/// Information extracted from a phone number
+pub use crate::phone::PhoneInfo;
 /// Sanitizer methods for strings
 pub use crate::string_sanitizer::StringSanitizer;
+/// Unit in which string truncation limits are counted
+pub use crate::truncate::TruncateUnit;
+/// Options for normalizing URLs
+pub use crate::uri::{TRACKING_PARAMS, UrlOptions};
+/// Line ending used when normalizing newlines
+pub use crate::whitespace::NewlineStyle;
+/// Output formats for phone number sanitizers
+pub use phonenumber::Mode as PhoneFormat;
+/// Type of a phone number, eg. mobile or fixed line
+pub use phonenumber::Type as PhoneType;
+/// Region used to parse phone numbers written in national format
+pub use phonenumber::country::Id as Region;
+/// The regex crate used by the regex sanitizers
+#[cfg(feature = "regex")]
+pub use regex;
+/// Unicode general category of a character
+pub use unicode_general_category::GeneralCategory;
+/// Unicode script of a character
+pub use unicode_script::Script;
 /// The Sanitizer trait generalises types that are to be sanitized.
 pub trait Sanitizer {
     /// Call this associated method when sanitizing.
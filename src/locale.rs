@@ -0,0 +1,272 @@
+use unicode_normalization::UnicodeNormalization;
+use unicode_normalization::char::canonical_combining_class;
+
+const COMBINING_DOT_ABOVE: char = '\u{0307}';
+
+// characters which lose their dot when an accent is placed above them
+static SOFT_DOTTED: [char; 12] = [
+    'i', 'j', '\u{012F}', '\u{0249}', '\u{0268}', '\u{029D}', '\u{02B2}', '\u{0456}', '\u{0458}',
+    '\u{1E2D}', '\u{1ECB}', '\u{2071}',
+];
+
+// accents and breathings dropped from greek letters when uppercasing
+static GREEK_ACCENTS: [char; 7] = [
+    '\u{0300}', '\u{0301}', '\u{0313}', '\u{0314}', '\u{0342}', '\u{0343}', '\u{0344}',
+];
+
+/// Language whose conventions are used for case conversion.
+///
+/// A `Locale` is parsed from a language tag such as `"tr"` or `"tr-TR"`,
+/// languages without special casing rules use [`Locale::Root`]. Being
+/// `Copy`, it can be kept in a request or user context and passed to
+/// [`StringSanitizer::to_lowercase_locale`](crate::StringSanitizer::to_lowercase_locale)
+/// and [`StringSanitizer::to_uppercase_locale`](crate::StringSanitizer::to_uppercase_locale).
+/// The derive macro reads it from a sibling field with
+/// `lower_case(locale_from = "field")`
+///
+/// # Example
+///
+/// ```
+/// use sanitizer::Locale;
+///
+/// assert_eq!(Locale::from("tr-TR"), Locale::Turkish);
+/// assert_eq!(Locale::from("en"), Locale::Root);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Locale {
+    /// Language independent case mappings
+    #[default]
+    Root,
+    /// Turkish (`tr`), dotted and dotless i are separate letters
+    Turkish,
+    /// Azerbaijani (`az`), same rules as Turkish
+    Azerbaijani,
+    /// Lithuanian (`lt`), keeps the dot of i when it carries an accent
+    Lithuanian,
+    /// Greek (`el`), drops accents when uppercasing
+    Greek,
+}
+
+impl From<&str> for Locale {
+    fn from(tag: &str) -> Self {
+        let language = tag.split(['-', '_']).next().unwrap_or_default();
+        match language.to_ascii_lowercase().as_str() {
+            "tr" => Self::Turkish,
+            "az" => Self::Azerbaijani,
+            "lt" => Self::Lithuanian,
+            "el" => Self::Greek,
+            _ => Self::Root,
+        }
+    }
+}
+
+impl From<&String> for Locale {
+    fn from(tag: &String) -> Self {
+        Self::from(tag.as_str())
+    }
+}
+
+impl From<&Locale> for Locale {
+    fn from(locale: &Locale) -> Self {
+        *locale
+    }
+}
+
+// the next char with an accent above, skipping other combining marks
+fn more_above(rest: &[char]) -> bool {
+    for character in rest {
+        match canonical_combining_class(*character) {
+            0 => return false,
+            230 => return true,
+            _ => {}
+        }
+    }
+    false
+}
+
+// whether the previous base character is soft dotted, skipping
+// combining marks other than accents above
+fn after_soft_dotted(before: &[char]) -> bool {
+    for character in before.iter().rev() {
+        match canonical_combining_class(*character) {
+            0 => return SOFT_DOTTED.contains(character),
+            230 => return false,
+            _ => {}
+        }
+    }
+    false
+}
+
+// the index of the dot above following an I, skipping combining marks other
+// than accents above (the After_I condition)
+fn dot_above_after_i(rest: &[char]) -> Option<usize> {
+    for (index, character) in rest.iter().enumerate() {
+        match canonical_combining_class(*character) {
+            0 | 230 => return (*character == COMBINING_DOT_ABOVE).then_some(index),
+            _ => {}
+        }
+    }
+    None
+}
+
+// whether the char is a greek letter
+fn is_greek(character: char) -> bool {
+    ('\u{0370}'..='\u{03FF}').contains(&character) || ('\u{1F00}'..='\u{1FFF}').contains(&character)
+}
+
+// split content before every character which is not a combining mark, so
+// each part is a base character followed by its marks
+fn clusters(content: &str) -> impl Iterator<Item = &str> {
+    let mut starts = content
+        .char_indices()
+        .filter(|(_, character)| canonical_combining_class(*character) == 0)
+        .map(|(index, _)| index)
+        .skip_while(|index| *index == 0)
+        .chain([content.len()]);
+    let mut start = 0;
+    std::iter::from_fn(move || {
+        let end = starts.next()?;
+        let cluster = &content[start..end];
+        start = end;
+        Some(cluster)
+    })
+    .filter(|cluster| !cluster.is_empty())
+}
+
+// lowercase following the conditional mappings of SpecialCasing.txt,
+// final sigma is handled by `str::to_lowercase`
+pub(crate) fn to_lowercase(content: &str, locale: Locale) -> String {
+    let chars: Vec<char> = content.chars().collect();
+    let mut mapped = String::with_capacity(content.len());
+    let mut index = 0;
+    while index < chars.len() {
+        let character = chars[index];
+        let rest = &chars[index + 1..];
+        match (locale, character) {
+            (Locale::Turkish | Locale::Azerbaijani, '\u{0130}') => mapped.push('i'),
+            (Locale::Turkish | Locale::Azerbaijani, 'I') => match dot_above_after_i(rest) {
+                Some(dot) => {
+                    mapped.push('i');
+                    mapped.extend(&rest[..dot]);
+                    index += dot + 1;
+                }
+                None => mapped.push('\u{0131}'),
+            },
+            (Locale::Lithuanian, 'I' | 'J' | '\u{012E}') if more_above(rest) => {
+                mapped.extend(character.to_lowercase());
+                mapped.push(COMBINING_DOT_ABOVE);
+            }
+            (Locale::Lithuanian, '\u{00CC}') => mapped.push_str("i\u{0307}\u{0300}"),
+            (Locale::Lithuanian, '\u{00CD}') => mapped.push_str("i\u{0307}\u{0301}"),
+            (Locale::Lithuanian, '\u{0128}') => mapped.push_str("i\u{0307}\u{0303}"),
+            _ => mapped.push(character),
+        }
+        index += 1;
+    }
+    mapped.to_lowercase()
+}
+
+// uppercase following the conditional mappings of SpecialCasing.txt, and
+// the CLDR rule of removing accents from greek
+pub(crate) fn to_uppercase(content: &str, locale: Locale) -> String {
+    match locale {
+        Locale::Root => content.to_uppercase(),
+        Locale::Turkish | Locale::Azerbaijani => content
+            .chars()
+            .map(|character| match character {
+                'i' => '\u{0130}',
+                character => character,
+            })
+            .collect::<String>()
+            .to_uppercase(),
+        Locale::Lithuanian => {
+            let chars: Vec<char> = content.chars().collect();
+            chars
+                .iter()
+                .enumerate()
+                .filter(|(index, character)| {
+                    **character != COMBINING_DOT_ABOVE || !after_soft_dotted(&chars[..*index])
+                })
+                .map(|(_, character)| *character)
+                .collect::<String>()
+                .to_uppercase()
+        }
+        Locale::Greek => {
+            let mut uppercase = String::with_capacity(content.len());
+            for cluster in clusters(content) {
+                if cluster.starts_with(is_greek) {
+                    // only greek letters are decomposed, to remove their
+                    // accents, so other text is not normalized
+                    let cluster: String = cluster
+                        .nfd()
+                        .filter(|character| !GREEK_ACCENTS.contains(character))
+                        .collect::<String>()
+                        .to_uppercase();
+                    uppercase.extend(cluster.nfc());
+                } else {
+                    uppercase.push_str(&cluster.to_uppercase());
+                }
+            }
+            uppercase
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn turkish() {
+        assert_eq!(
+            "\u{131}istanbul",
+            to_lowercase("I\u{130}stanbul", Locale::Turkish)
+        );
+        assert_eq!(
+            "istanbul",
+            to_lowercase("I\u{307}stanbul", Locale::Azerbaijani)
+        );
+        assert_eq!("\u{130}STANBUL", to_uppercase("istanbul", Locale::Turkish));
+        assert_eq!("i\u{316}", to_lowercase("I\u{316}\u{307}", Locale::Turkish));
+        assert_eq!(
+            "\u{131}\u{301}\u{307}",
+            to_lowercase("I\u{301}\u{307}", Locale::Turkish)
+        );
+    }
+
+    #[test]
+    fn lithuanian() {
+        assert_eq!(
+            "i\u{307}\u{301}",
+            to_lowercase("I\u{301}", Locale::Lithuanian)
+        );
+        assert_eq!(
+            "i\u{307}\u{300}",
+            to_lowercase("\u{CC}", Locale::Lithuanian)
+        );
+        assert_eq!("i", to_lowercase("I", Locale::Lithuanian));
+        assert_eq!(
+            "I\u{301}",
+            to_uppercase("i\u{307}\u{301}", Locale::Lithuanian)
+        );
+    }
+
+    #[test]
+    fn greek() {
+        assert_eq!("ΟΔΥΣΣΕΥΣ", to_uppercase("Οδυσσεύς", Locale::Greek));
+        assert_eq!("οδυσσεύς", to_lowercase("ΟΔΥΣΣΕΎΣ", Locale::Greek));
+        assert_eq!(
+            "\u{391}\u{3AA}",
+            to_uppercase("\u{3B1}\u{390}", Locale::Greek)
+        );
+        assert_eq!("E\u{301}", to_uppercase("e\u{301}", Locale::Greek));
+        assert_eq!("\u{301}Α", to_uppercase("\u{301}ά", Locale::Greek));
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(Locale::Turkish, Locale::from("TR"));
+        assert_eq!(Locale::Lithuanian, Locale::from("lt_LT"));
+        assert_eq!(Locale::Root, Locale::from(""));
+    }
+}
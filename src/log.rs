@@ -0,0 +1,143 @@
+use crate::TruncateUnit;
+use crate::truncate::truncate;
+use crate::unicode::is_bidi_control;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::iter::Peekable;
+use std::str::Chars;
+
+/// Number of characters [`StringSanitizer::log_safe`](crate::StringSanitizer::log_safe)
+/// and [`LogSafe`] keep by default
+pub const LOG_SAFE_LIMIT: usize = 1024;
+
+const ESCAPE: char = '\u{1B}';
+
+/// Displays a value made safe for log lines like
+/// [`StringSanitizer::log_safe`](crate::StringSanitizer::log_safe), without
+/// changing the value. The value is only escaped when it is formatted
+///
+/// # Example
+///
+/// ```
+/// use sanitizer::LogSafe;
+///
+/// let username = "admin\nINFO login succeeded";
+/// assert_eq!(
+///     format!("login failed for {}", LogSafe(username)),
+///     "login failed for admin\\nINFO login succeeded"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LogSafe<T>(pub T);
+
+impl<T: Display> Display for LogSafe<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(f, "{}", log_safe(&self.0.to_string(), LOG_SAFE_LIMIT))
+    }
+}
+
+// skip the rest of a string sequence such as OSC, which ends with BEL or
+// ESC \
+fn skip_string_sequence(chars: &mut Peekable<Chars>) {
+    while let Some(character) = chars.next() {
+        match character {
+            '\u{7}' => return,
+            ESCAPE if chars.next_if_eq(&'\\').is_some() => return,
+            _ => {}
+        }
+    }
+}
+
+// skip the parameters and the final byte of a CSI sequence
+fn skip_csi_sequence(chars: &mut Peekable<Chars>) {
+    for character in chars.by_ref() {
+        if ('\u{40}'..='\u{7E}').contains(&character) {
+            return;
+        }
+    }
+}
+
+// skip the rest of a sequence starting with ESC
+fn skip_escape_sequence(chars: &mut Peekable<Chars>) {
+    match chars.next() {
+        Some('[') => skip_csi_sequence(chars),
+        Some(']' | 'P' | 'X' | '^' | '_') => skip_string_sequence(chars),
+        // intermediate bytes followed by a final byte, eg. ESC ( B
+        Some('\u{20}'..='\u{2F}') => {
+            while chars
+                .next_if(|next| ('\u{20}'..='\u{2F}').contains(next))
+                .is_some()
+            {}
+            chars.next();
+        }
+        _ => {}
+    }
+}
+
+// see StringSanitizer::log_safe_with_limit
+pub(crate) fn log_safe(content: &str, limit: usize) -> String {
+    let mut safe = String::with_capacity(content.len());
+    let mut chars = content.chars().peekable();
+    while let Some(character) = chars.next() {
+        match character {
+            // escaped first, so escaped line breaks cannot be forged
+            '\\' => safe.push_str("\\\\"),
+            '\r' => safe.push_str("\\r"),
+            '\n' | '\u{85}' | '\u{2028}' | '\u{2029}' => safe.push_str("\\n"),
+            '\t' => safe.push_str("\\t"),
+            ESCAPE => skip_escape_sequence(&mut chars),
+            '\u{9B}' => skip_csi_sequence(&mut chars),
+            character if character.is_control() || is_bidi_control(character) => {}
+            character => safe.push(character),
+        }
+    }
+    truncate(&safe, limit, TruncateUnit::Chars, "…")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn line_breaks() {
+        assert_eq!(
+            "a\\r\\nb\\nc\\td",
+            log_safe("a\r\nb\u{2028}c\td", LOG_SAFE_LIMIT)
+        );
+    }
+
+    #[test]
+    fn backslashes() {
+        assert_eq!("a\\\\nb", log_safe("a\\nb", LOG_SAFE_LIMIT));
+        assert_ne!(
+            log_safe("a\nb", LOG_SAFE_LIMIT),
+            log_safe("a\\nb", LOG_SAFE_LIMIT)
+        );
+    }
+
+    #[test]
+    fn escape_sequences() {
+        assert_eq!(
+            "red bold",
+            log_safe("\u{1B}[31mred\u{1B}[0m \u{1B}[1;4mbold", LOG_SAFE_LIMIT)
+        );
+        assert_eq!(
+            "title",
+            log_safe("\u{1B}]0;evil\u{7}title\u{1B}(B", LOG_SAFE_LIMIT)
+        );
+        assert_eq!("ab", log_safe("a\u{9B}2Jb\u{0}\u{202E}", LOG_SAFE_LIMIT));
+        assert_eq!("a", log_safe("a\u{1B}", LOG_SAFE_LIMIT));
+    }
+
+    #[test]
+    fn limit() {
+        assert_eq!("abc…", log_safe("abcdefgh", 4));
+        assert_eq!("a\\nb", log_safe("a\nb", 4));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!("42\\n", LogSafe("42\n").to_string());
+        assert_eq!("7", format!("{}", LogSafe(7)));
+    }
+}
@@ -0,0 +1,248 @@
+use html_escape::decode_html_entities;
+#[cfg(feature = "markdown")]
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+
+// elements which start and end on their own lines
+static BLOCK_ELEMENTS: [&str; 28] = [
+    "address",
+    "article",
+    "aside",
+    "blockquote",
+    "div",
+    "dl",
+    "fieldset",
+    "figcaption",
+    "figure",
+    "footer",
+    "form",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "header",
+    "main",
+    "nav",
+    "ol",
+    "p",
+    "pre",
+    "section",
+    "table",
+    "tbody",
+    "thead",
+    "ul",
+];
+
+// elements which start a new line
+static LINE_ELEMENTS: [&str; 6] = ["br", "dd", "dt", "hr", "li", "tr"];
+
+// elements whose content is not text
+static RAW_TEXT_ELEMENTS: [&str; 5] = ["script", "style", "template", "textarea", "title"];
+
+// append text, collapsing whitespace like a browser
+fn push_text(text: &mut String, content: &str) {
+    for character in content.chars() {
+        if character.is_ascii_whitespace() {
+            if !text.is_empty() && !text.ends_with([' ', '\n']) {
+                text.push(' ');
+            }
+        } else {
+            text.push(character);
+        }
+    }
+}
+
+// the index of the `>` closing the tag, ignoring `>` in quoted attributes
+fn tag_end(tag: &str) -> Option<usize> {
+    let mut quote = None;
+    for (index, character) in tag.char_indices() {
+        match (quote, character) {
+            (None, '"' | '\'') => quote = Some(character),
+            (Some(open), _) if open == character => quote = None,
+            (None, '>') => return Some(index),
+            _ => {}
+        }
+    }
+    None
+}
+
+// the lowercase name of a tag like `<a href="">` or `</a>`, and whether it
+// is a closing tag
+fn tag_name(tag: &str) -> (String, bool) {
+    let (tag, closing) = match tag.strip_prefix('/') {
+        Some(tag) => (tag, true),
+        None => (tag, false),
+    };
+    let name = tag
+        .chars()
+        .take_while(|character| character.is_ascii_alphanumeric())
+        .map(|character| character.to_ascii_lowercase())
+        .collect();
+    (name, closing)
+}
+
+// the content after the closing tag of a raw text element, the name is
+// matched ignoring case
+fn skip_element<'a>(content: &'a str, name: &str) -> &'a str {
+    let closing = content.match_indices("</").find(|(start, _)| {
+        content[start + 2..]
+            .get(..name.len())
+            .is_some_and(|tag| tag.eq_ignore_ascii_case(name))
+    });
+    match closing {
+        Some((start, _)) => {
+            let rest = &content[start..];
+            tag_end(rest).map_or("", |end| &rest[end + 1..])
+        }
+        None => "",
+    }
+}
+
+// trim lines and allow at most one empty line between paragraphs
+fn tidy_lines(text: &str) -> String {
+    let mut tidy = String::with_capacity(text.len());
+    let mut empty_lines = 0;
+    for line in text.trim().lines() {
+        let line = line.trim_end();
+        if line.is_empty() {
+            empty_lines += 1;
+            if empty_lines > 1 {
+                continue;
+            }
+        } else {
+            empty_lines = 0;
+        }
+        if !tidy.is_empty() {
+            tidy.push('\n');
+        }
+        tidy.push_str(line);
+    }
+    tidy
+}
+
+// see StringSanitizer::strip_html
+pub(crate) fn strip_html(content: &str) -> String {
+    let mut text = String::with_capacity(content.len());
+    let mut rest = content;
+    while let Some(start) = rest.find('<') {
+        push_text(&mut text, &rest[..start]);
+        let tag = &rest[start..];
+        if let Some(comment) = tag.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+        // a `<` which does not start a tag is text, eg. "1 < 2"
+        let starts_tag = tag[1..]
+            .chars()
+            .next()
+            .is_some_and(|next| next.is_ascii_alphabetic() || matches!(next, '/' | '!' | '?'));
+        if !starts_tag {
+            push_text(&mut text, "<");
+            rest = &tag[1..];
+            continue;
+        }
+        // without a `>` no later tag can end either, so the rest is text
+        // and is not scanned again for every `<`
+        let Some(end) = tag_end(tag) else {
+            rest = tag;
+            break;
+        };
+        let (name, closing) = tag_name(&tag[1..end]);
+        rest = &tag[end + 1..];
+        if !closing && RAW_TEXT_ELEMENTS.contains(&name.as_str()) {
+            rest = skip_element(rest, &name);
+        } else if BLOCK_ELEMENTS.contains(&name.as_str())
+            || (!closing && LINE_ELEMENTS.contains(&name.as_str()))
+        {
+            text.push('\n');
+        }
+    }
+    push_text(&mut text, rest);
+    tidy_lines(&decode_html_entities(&text))
+}
+
+// see StringSanitizer::strip_markdown
+#[cfg(feature = "markdown")]
+pub(crate) fn strip_markdown(content: &str) -> String {
+    let options = Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TABLES;
+    let mut text = String::with_capacity(content.len());
+    // an html block arrives line by line, it is stripped as a whole so
+    // that the body of a <script> or <style> spanning lines is removed
+    let mut html_block = String::new();
+    for event in Parser::new_ext(content, options) {
+        match event {
+            Event::Text(content) | Event::Code(content) => text.push_str(&content),
+            Event::Html(html) => html_block.push_str(&html),
+            Event::End(TagEnd::HtmlBlock) => {
+                text.push_str(&strip_html(&html_block));
+                text.push_str("\n\n");
+                html_block.clear();
+            }
+            Event::InlineHtml(html) => text.push_str(&strip_html(&html)),
+            Event::SoftBreak => text.push(' '),
+            Event::HardBreak | Event::Rule | Event::Start(Tag::Item) => text.push('\n'),
+            Event::End(TagEnd::TableCell) => text.push(' '),
+            Event::End(TagEnd::TableHead | TagEnd::TableRow | TagEnd::List(_)) => text.push('\n'),
+            Event::End(
+                TagEnd::Paragraph
+                | TagEnd::Heading(_)
+                | TagEnd::CodeBlock
+                | TagEnd::BlockQuote(_)
+                | TagEnd::Table,
+            ) => text.push_str("\n\n"),
+            _ => {}
+        }
+    }
+    tidy_lines(&text)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn html_tags() {
+        assert_eq!(
+            "Title\n\nHello World & you\n\nOne\nTwo",
+            strip_html(
+                "<h1>Title</h1>\n<p class=\"x\">Hello\n   <b>World</b> &amp; you</p><ul><li>One</li><li>Two</li></ul>"
+            )
+        );
+        assert_eq!(
+            "a\nb",
+            strip_html("<script>alert('<p>')</script>a<br/><!-- <p> -->b<style>p{}</style>")
+        );
+        assert_eq!("1 < 2 > 0", strip_html("1 < 2 > 0"));
+        assert_eq!("Link", strip_html("<a title=\"a > b\">Link</a>"));
+        assert_eq!("<b>", strip_html("&lt;b&gt;"));
+        assert_eq!("a <b", strip_html("a <b"));
+        assert_eq!("x", strip_html("<SCRIPT>alert(1)</Script >x"));
+    }
+
+    #[test]
+    fn hostile_input() {
+        // both used to take quadratic time
+        let unclosed = "<a".repeat(200_000);
+        assert_eq!(unclosed, strip_html(&unclosed));
+        let scripts = "<script></script>".repeat(200_000);
+        assert_eq!("", strip_html(&scripts));
+    }
+
+    #[cfg(feature = "markdown")]
+    #[test]
+    fn markdown() {
+        assert_eq!(
+            "Title\n\nSome emphasis and a link.\n\nfn main() {}\n\nOne\nTwo",
+            strip_markdown(
+                "# Title\n\nSome *emphasis* and\na [link](https://example.com).\n\n```rust\nfn main() {}\n```\n\n- One\n- **Two**\n"
+            )
+        );
+        assert_eq!(
+            "Intro\n\nsome html\n\ntext",
+            strip_markdown(
+                "Intro\n\n<script>\nalert(1)\n</script>\n\n<div>\nsome html\n</div>\n\ntext"
+            )
+        );
+    }
+}
@@ -0,0 +1,151 @@
+use crate::Error;
+use crate::TruncateUnit;
+use crate::truncate::prefix;
+
+/// Number of bytes [`StringSanitizer::filename`](crate::StringSanitizer::filename)
+/// keeps by default, the limit of most file systems
+pub const FILENAME_LIMIT: usize = 255;
+
+// longest extension kept when a filename is cut
+const MAX_EXTENSION_LEN: usize = 16;
+
+// characters Windows does not allow in filenames
+static RESERVED_CHARS: [char; 7] = ['<', '>', ':', '"', '|', '?', '*'];
+
+// device names Windows does not allow as filenames, with any extension.
+// The superscript digits are treated as digits by Windows
+static RESERVED_NAMES: [&str; 30] = [
+    "CON", "PRN", "AUX", "NUL", "CONIN$", "CONOUT$", "COM1", "COM2", "COM3", "COM4", "COM5",
+    "COM6", "COM7", "COM8", "COM9", "COM¹", "COM²", "COM³", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5",
+    "LPT6", "LPT7", "LPT8", "LPT9", "LPT¹", "LPT²", "LPT³",
+];
+
+fn is_separator(character: char) -> bool {
+    character == '/' || character == '\\'
+}
+
+fn is_reserved_name(name: &str) -> bool {
+    let stem = name.split('.').next().unwrap_or_default().trim_end();
+    RESERVED_NAMES
+        .iter()
+        .any(|reserved| reserved.eq_ignore_ascii_case(stem))
+}
+
+// cut the name to the limit, keeping a short extension
+fn limit_name(name: &str, limit: usize) -> String {
+    if name.len() <= limit {
+        return name.to_owned();
+    }
+    let extension = match name.rfind('.') {
+        Some(dot) if dot > 0 && name.len() - dot <= MAX_EXTENSION_LEN.min(limit) => &name[dot..],
+        _ => "",
+    };
+    let stem = &name[..name.len() - extension.len()];
+    let stem = prefix(stem, limit - extension.len(), TruncateUnit::Bytes);
+    format!("{}{}", stem.trim_end_matches(['.', ' ']), extension)
+}
+
+// a single path component without control and reserved characters,
+// trailing dots and spaces, or reserved device names
+fn clean_component(component: &str, limit: usize) -> String {
+    let cleaned: String = component
+        .chars()
+        .filter(|character| !character.is_control() && !RESERVED_CHARS.contains(character))
+        .collect();
+    let cleaned = limit_name(cleaned.trim_start().trim_end_matches(['.', ' ']), limit);
+    if is_reserved_name(&cleaned) {
+        limit_name(&format!("_{}", cleaned), limit)
+    } else {
+        cleaned
+    }
+}
+
+// see StringSanitizer::filename_with_limit
+pub(crate) fn filename(content: &str, limit: usize) -> String {
+    let components: Vec<&str> = content
+        .split(is_separator)
+        .filter(|component| !matches!(component.trim(), "" | "." | ".."))
+        .collect();
+    clean_component(&components.join("_"), limit)
+}
+
+// see StringSanitizer::try_relative_path, `..` at the root is an error
+// unless it is dropped with `clamp`
+pub(crate) fn relative_path(content: &str, clamp: bool) -> Result<String, Error> {
+    let mut components: Vec<String> = Vec::new();
+    for component in content.split(is_separator) {
+        match component.trim() {
+            "" | "." => {}
+            ".." => {
+                if components.pop().is_none() && !clamp {
+                    return Err(Error::PathEscapesRoot);
+                }
+            }
+            _ => {
+                let component = clean_component(component, FILENAME_LIMIT);
+                if !component.is_empty() {
+                    components.push(component);
+                }
+            }
+        }
+    }
+    Ok(components.join("/"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn traversal() {
+        assert_eq!("etc_passwd", filename("../../etc/passwd", FILENAME_LIMIT));
+        assert_eq!("a_b.png", filename("a\\b.png", FILENAME_LIMIT));
+        assert_eq!("", filename("..", FILENAME_LIMIT));
+        assert_eq!(".env", filename(".env", FILENAME_LIMIT));
+    }
+
+    #[test]
+    fn reserved() {
+        assert_eq!("_CON.txt", filename("CON.txt", FILENAME_LIMIT));
+        assert_eq!("_lpt1", filename("lpt1", FILENAME_LIMIT));
+        assert_eq!("CONSOLE.txt", filename("CONSOLE.txt", FILENAME_LIMIT));
+        assert_eq!("foo.png", filename("foo\0.png", FILENAME_LIMIT));
+        assert_eq!("what.txt", filename(" what?.txt. . ", FILENAME_LIMIT));
+        assert_eq!("_CONIN$", filename("CONIN$", FILENAME_LIMIT));
+        assert_eq!("_conout$.log", filename("conout$.log", FILENAME_LIMIT));
+        assert_eq!("_COM¹.txt", filename("COM¹.txt", FILENAME_LIMIT));
+        assert_eq!("_lpt³", filename("lpt³", FILENAME_LIMIT));
+    }
+
+    #[test]
+    fn limit() {
+        let long = format!("{}.jpeg", "a".repeat(300));
+        let limited = filename(&long, FILENAME_LIMIT);
+        assert_eq!(255, limited.len());
+        assert!(limited.ends_with("a.jpeg"));
+        assert_eq!("_CO.txt", filename("CONSOLE.txt", 7));
+        assert_eq!("ab", filename("ab.verylongextensionname", 2));
+        assert_eq!("é.txt", filename("éé.txt", 7));
+    }
+
+    #[test]
+    fn paths() {
+        assert_eq!(
+            Ok(String::from("a/c")),
+            relative_path("/a/./b/../c/", false)
+        );
+        assert_eq!(
+            Ok(String::from("docs/_AUX")),
+            relative_path("docs\\AUX", false)
+        );
+        assert_eq!(
+            Err(Error::PathEscapesRoot),
+            relative_path("a/../../etc", false)
+        );
+        assert_eq!(
+            Ok(String::from("etc/passwd")),
+            relative_path("../../etc/passwd", true)
+        );
+        assert_eq!(Ok(String::from("b")), relative_path("a/../../b", true));
+    }
+}
@@ -0,0 +1,150 @@
+// particles which stay lower case unless they start the name
+static PARTICLES: [&str; 22] = [
+    "al", "bin", "binti", "da", "das", "de", "del", "della", "den", "der", "di", "do", "dos", "du",
+    "ibn", "la", "le", "ten", "ter", "van", "von", "y",
+];
+
+// Gaelic names written with a "Mac" prefix, other names starting with
+// "mac" (Machado, Macedo, Machiavelli, ...) are capitalized as usual
+static MAC_NAMES: [&str; 40] = [
+    "macallister",
+    "macarthur",
+    "macaskill",
+    "macbain",
+    "macbeth",
+    "maccallum",
+    "maccormick",
+    "macdermott",
+    "macdonald",
+    "macdougall",
+    "macduff",
+    "macewan",
+    "macfarlane",
+    "macgillivray",
+    "macgregor",
+    "macinnes",
+    "macintosh",
+    "macintyre",
+    "mackay",
+    "mackenzie",
+    "mackinnon",
+    "maclachlan",
+    "maclaren",
+    "maclean",
+    "maclennan",
+    "macleod",
+    "macmillan",
+    "macnab",
+    "macnamara",
+    "macneil",
+    "macneill",
+    "macnicol",
+    "macpherson",
+    "macquarrie",
+    "macqueen",
+    "macrae",
+    "macritchie",
+    "macsween",
+    "mactaggart",
+    "macvicar",
+];
+
+fn capitalize(segment: &str) -> String {
+    let mut chars = segment.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+fn find_exception(word: &str, exceptions: &[&str]) -> Option<String> {
+    exceptions
+        .iter()
+        .find(|exception| exception.to_lowercase() == word)
+        .map(|exception| exception.to_string())
+}
+
+// capitalize a lower case part of a name, handling the Mc and Mac prefixes
+fn capitalize_segment(segment: &str, exceptions: &[&str]) -> String {
+    let trimmed = segment.trim_end_matches(['-', '\'', '’']);
+    if let Some(exception) = find_exception(trimmed, exceptions) {
+        return exception + &segment[trimmed.len()..];
+    }
+    let letters = trimmed.chars().count();
+    if let Some(rest) = segment.strip_prefix("mc").filter(|_| letters > 3) {
+        format!("Mc{}", capitalize(rest))
+    } else if let Some(rest) = segment
+        .strip_prefix("mac")
+        .filter(|_| MAC_NAMES.contains(&trimmed))
+    {
+        format!("Mac{}", capitalize(rest))
+    } else {
+        capitalize(segment)
+    }
+}
+
+// capitalize a personal name, see StringSanitizer::person_name
+pub(crate) fn person_name(content: &str, exceptions: &[&str]) -> String {
+    content
+        .split_whitespace()
+        .enumerate()
+        .map(|(index, word)| {
+            let word = word.to_lowercase();
+            if let Some(exception) = find_exception(&word, exceptions) {
+                exception
+            } else if index > 0 && PARTICLES.contains(&word.as_str()) {
+                word
+            } else {
+                word.split_inclusive(['-', '\'', '’'])
+                    .map(|segment| capitalize_segment(segment, exceptions))
+                    .collect()
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn names() {
+        assert_eq!("O'Brien", person_name("o'brien", &[]));
+        assert_eq!("McDonald", person_name("MCDONALD", &[]));
+        assert_eq!("MacDonald", person_name("macdonald", &[]));
+        assert_eq!("Mack Macy", person_name("mack macy", &[]));
+        assert_eq!(
+            "Ludwig van der Berg",
+            person_name("ludwig  van DER berg", &[])
+        );
+        assert_eq!("Van Morrison", person_name("van morrison", &[]));
+        assert_eq!("Jean-Luc Picard", person_name("jean-luc\tpicard", &[]));
+        assert_eq!("Ahmad bin Ali", person_name("AHMAD BIN ALI", &[]));
+    }
+
+    #[test]
+    fn mac() {
+        assert_eq!("MacLeod-MacKenzie", person_name("macleod-mackenzie", &[]));
+        assert_eq!("Machiavelli", person_name("machiavelli", &[]));
+        assert_eq!(
+            "Macedo Macaluso Macario",
+            person_name("MACEDO MACALUSO MACARIO", &[])
+        );
+        assert_eq!(
+            "Machin Macrina Macchia",
+            person_name("machin macrina macchia", &[])
+        );
+    }
+
+    #[test]
+    fn exceptions() {
+        let exceptions = ["DeVito", "Machiavelli"];
+        assert_eq!("Danny DeVito", person_name("danny devito", &exceptions));
+        assert_eq!(
+            "Niccolò Machiavelli",
+            person_name("niccolò machiavelli", &exceptions)
+        );
+        assert_eq!("Smith-DeVito", person_name("smith-devito", &exceptions));
+    }
+}
@@ -0,0 +1,67 @@
+use crate::{Error, Region};
+use phonenumber::metadata::DATABASE;
+use phonenumber::{Mode, PhoneNumber, Type, parse};
+
+/// Information extracted from a phone number by
+/// [`StringSanitizer::phone_info`](crate::StringSanitizer::phone_info)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PhoneInfo {
+    /// The number in the E164 International Standard
+    pub e164: String,
+    /// The country calling code, eg. `44` for the United Kingdom
+    pub country_code: u16,
+    /// The region the number belongs to, if it can be determined
+    pub region: Option<Region>,
+    /// The type of the number, eg. mobile, fixed line or toll free
+    pub number_type: Type,
+    /// Whether the number belongs to an assigned number range
+    pub is_valid: bool,
+    /// Whether the number has a possible length for its country code,
+    /// valid numbers are always possible
+    pub is_possible: bool,
+}
+
+impl From<&PhoneNumber> for PhoneInfo {
+    fn from(number: &PhoneNumber) -> Self {
+        let is_valid = number.is_valid();
+        Self {
+            e164: number.format().mode(Mode::E164).to_string(),
+            country_code: number.code().value(),
+            region: number.country().id(),
+            number_type: number.number_type(&DATABASE),
+            is_valid,
+            is_possible: is_valid || is_possible_number(number),
+        }
+    }
+}
+
+pub(crate) fn parse_valid_number(
+    region: Option<Region>,
+    content: &str,
+) -> Result<PhoneNumber, Error> {
+    let number = parse(region, content).map_err(|_| Error::InvalidPhoneNumber)?;
+    if number.is_valid() {
+        Ok(number)
+    } else if is_possible_number(&number) {
+        Err(Error::UnassignedPhoneNumber)
+    } else {
+        Err(Error::InvalidPhoneNumber)
+    }
+}
+
+// a number is possible when its length matches the numbers of its region
+fn is_possible_number(number: &PhoneNumber) -> bool {
+    let length = number.national().to_string().len() as u16;
+    DATABASE
+        .by_code(&number.code().value())
+        .unwrap_or_default()
+        .iter()
+        .any(|meta| {
+            let descriptors = meta.descriptors();
+            [descriptors.fixed_line(), descriptors.mobile()]
+                .into_iter()
+                .flatten()
+                .chain(Some(descriptors.general()))
+                .any(|descriptor| descriptor.possible_length().contains(&length))
+        })
+}
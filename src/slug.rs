@@ -0,0 +1,54 @@
+use deunicode::deunicode_with_tofu;
+
+// see StringSanitizer::slugify_with
+pub(crate) fn slugify(content: &str, separator: &str, max: Option<usize>) -> String {
+    let ascii = deunicode_with_tofu(content, "").to_ascii_lowercase();
+    let mut slug = String::with_capacity(ascii.len());
+    let words = ascii
+        .split(|character: char| !character.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty());
+    for word in words {
+        let needed = if slug.is_empty() {
+            word.len()
+        } else {
+            separator.len() + word.len()
+        };
+        if let Some(max) = max {
+            if slug.len() + needed > max {
+                // a single word longer than the limit is cut, later words
+                // are dropped as a whole
+                if slug.is_empty() {
+                    slug.push_str(&word[..max]);
+                }
+                break;
+            }
+        }
+        if !slug.is_empty() {
+            slug.push_str(separator);
+        }
+        slug.push_str(word);
+    }
+    slug
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn separators() {
+        assert_eq!(
+            "creme-brulee-recipe",
+            slugify("  Crème -- Brûlée: Recipe! ", "-", None)
+        );
+        assert_eq!("hello_world", slugify("Hello, World", "_", None));
+        assert_eq!("", slugify("!?", "-", None));
+    }
+
+    #[test]
+    fn max_length() {
+        assert_eq!("hello-big", slugify("Hello big world", "-", Some(12)));
+        assert_eq!("hello-big-world", slugify("Hello big world", "-", Some(15)));
+        assert_eq!("hel", slugify("Hello big world", "-", Some(3)));
+    }
+}
@@ -1,8 +1,46 @@
+#[cfg(feature = "html")]
+use crate::HtmlPolicy;
+use crate::case::to_case;
+use crate::char_class::filter;
+use crate::csv::csv_safe;
+use crate::email::{email, email_canonical};
+use crate::hostname::{hostname, hostname_unicode, strip_www};
+#[cfg(feature = "html")]
+use crate::html::html_sanitize;
+use crate::locale;
+use crate::log::log_safe;
+use crate::markup::strip_html;
+#[cfg(feature = "markdown")]
+use crate::markup::strip_markdown;
+use crate::path::{filename, relative_path};
+use crate::person_name::person_name;
+use crate::phone::{PhoneInfo, parse_valid_number};
+use crate::slug::slugify;
+use crate::truncate::{prefix, truncate, truncate_words};
+use crate::unicode::{is_bidi_control, is_default_ignorable, strip_invisible};
+use crate::uri::url;
+use crate::whitespace::{
+    collapse_whitespace, max_consecutive_newlines, normalize_newlines, normalize_whitespace,
+    strip_trailing_whitespace_per_line,
+};
+use crate::{
+    CharClass, CsvSafeOptions, Error, FILENAME_LIMIT, LOG_SAFE_LIMIT, Locale, NewlineStyle,
+    PhoneFormat, Region, TruncateUnit, UrlOptions, WordCase,
+};
+use caseless::Caseless;
+use deunicode::deunicode_with_tofu;
 use heck::*;
+use html_escape::{decode_html_entities, encode_quoted_attribute};
 use phonenumber::{Mode, parse};
+#[cfg(feature = "regex")]
+use regex::Regex;
+use std::borrow::Cow;
 use std::cmp::PartialEq;
 use std::convert::From;
 use std::ops::Deref;
+use unicode_normalization::{
+    IsNormalized, UnicodeNormalization, is_nfc_quick, is_nfd_quick, is_nfkc_quick, is_nfkd_quick,
+};
 
 /// The Sanitizer structure is a wrapper over a String type which is to
 /// be sanitized.
@@ -37,6 +75,97 @@ impl StringSanitizer {
         self.0 = self.0.trim().to_string();
         self
     }
+    /// Trim whitespace from the start of the string
+    pub fn trim_start(&mut self) -> &mut Self {
+        self.0 = self.0.trim_start().to_string();
+        self
+    }
+    /// Trim whitespace from the end of the string
+    pub fn trim_end(&mut self) -> &mut Self {
+        self.0 = self.0.trim_end().to_string();
+        self
+    }
+    /// Trim all of the given characters from both ends of the string
+    ///
+    /// ```
+    /// use sanitizer::prelude::*;
+    ///
+    /// let mut instance = StringSanitizer::from("--_hello_world-");
+    /// instance.trim_matches("-_");
+    /// assert_eq!(instance.get(), "hello_world");
+    /// ```
+    pub fn trim_matches(&mut self, chars: &str) -> &mut Self {
+        self.0 = self
+            .0
+            .trim_matches(|character| chars.contains(character))
+            .to_string();
+        self
+    }
+    /// Replace every run of whitespace, including tabs, line breaks, no
+    /// break and ideographic spaces, with a single ASCII space. The ends
+    /// are not trimmed, chain [`trim`](Self::trim) for that
+    pub fn collapse_whitespace(&mut self) -> &mut Self {
+        self.0 = collapse_whitespace(&self.0);
+        self
+    }
+    /// Like [`collapse_whitespace`](Self::collapse_whitespace), but if
+    /// `keep_newlines` is set runs containing a line break become a single
+    /// `\n`, and runs containing more become a paragraph break (`\n\n`)
+    pub fn normalize_whitespace(&mut self, keep_newlines: bool) -> &mut Self {
+        self.0 = normalize_whitespace(&self.0, keep_newlines);
+        self
+    }
+    /// Convert every line ending (`\r\n`, `\r`, `\n`, U+0085, U+2028 and
+    /// U+2029) to the given style
+    pub fn normalize_newlines(&mut self, style: NewlineStyle) -> &mut Self {
+        self.0 = normalize_newlines(&self.0, style);
+        self
+    }
+    /// Limit runs of line breaks to at most `max` consecutive line breaks,
+    /// `\r\n` counts as one. Lines containing only whitespace interrupt
+    /// the run, chain
+    /// [`strip_trailing_whitespace_per_line`](Self::strip_trailing_whitespace_per_line)
+    /// first to count them too
+    pub fn max_consecutive_newlines(&mut self, max: usize) -> &mut Self {
+        self.0 = max_consecutive_newlines(&self.0, max);
+        self
+    }
+    /// Remove the whitespace at the end of every line
+    pub fn strip_trailing_whitespace_per_line(&mut self) -> &mut Self {
+        self.0 = strip_trailing_whitespace_per_line(&self.0);
+        self
+    }
+    /// Remove control characters: the C0 controls (U+0000 to U+001F,
+    /// including tabs and line breaks), DEL (U+007F) and the C1 controls
+    /// (U+0080 to U+009F)
+    pub fn strip_control(&mut self) -> &mut Self {
+        self.strip_control_except("")
+    }
+    /// Like [`strip_control`](Self::strip_control), but keeps the given
+    /// characters, eg. `"\n\t"` to keep line breaks and tabs
+    pub fn strip_control_except(&mut self, keep: &str) -> &mut Self {
+        self.0
+            .retain(|character| !character.is_control() || keep.contains(character));
+        self
+    }
+    /// Remove invisible characters: every Unicode default ignorable code
+    /// point, such as zero width spaces, the byte order mark, soft hyphens
+    /// and bidi controls. The zero width joiner is kept since emoji are
+    /// built from it, and so is a variation selector right after a visible
+    /// character or a complete emoji tag sequence such as the subdivision
+    /// of a flag. Other variation selectors and tag characters are removed,
+    /// as they can hide text
+    pub fn strip_invisible(&mut self) -> &mut Self {
+        self.0 = strip_invisible(&self.0);
+        self
+    }
+    /// Remove the bidirectional formatting characters (U+061C, U+200E,
+    /// U+200F, U+202A to U+202E and U+2066 to U+2069) which can be used to
+    /// make text display differently from its logical order
+    pub fn strip_bidi_controls(&mut self) -> &mut Self {
+        self.0.retain(|character| !is_bidi_control(character));
+        self
+    }
     /// Remove non numeric characters from the string
     pub fn numeric(&mut self) -> &mut Self {
         self.0 = self.0.chars().filter(|b| b.is_numeric()).collect();
@@ -47,6 +176,27 @@ impl StringSanitizer {
         self.0 = self.0.chars().filter(|b| b.is_alphanumeric()).collect();
         self
     }
+    /// Remove all characters except the ASCII digits `0-9`. Unlike
+    /// [`numeric`](Self::numeric) digits of other scripts like "٣" are removed
+    pub fn ascii_numeric(&mut self) -> &mut Self {
+        self.0.retain(|character| character.is_ascii_digit());
+        self
+    }
+    /// Remove all characters except ASCII letters and digits
+    pub fn ascii_alphanumeric(&mut self) -> &mut Self {
+        self.0.retain(|character| character.is_ascii_alphanumeric());
+        self
+    }
+    /// Remove all characters which do not belong to any of the classes
+    pub fn keep(&mut self, classes: &[CharClass]) -> &mut Self {
+        self.0 = filter(&self.0, classes, true);
+        self
+    }
+    /// Remove all characters which belong to any of the classes
+    pub fn remove(&mut self, classes: &[CharClass]) -> &mut Self {
+        self.0 = filter(&self.0, classes, false);
+        self
+    }
     /// Convert string to lower case
     pub fn to_lowercase(&mut self) -> &mut Self {
         self.0 = self.0.to_lowercase();
@@ -57,6 +207,85 @@ impl StringSanitizer {
         self.0 = self.0.to_uppercase();
         self
     }
+    /// Convert string to lower case following the rules of the language,
+    /// eg. Turkish "I" becomes dotless "ı"
+    ///
+    /// ```
+    /// use sanitizer::prelude::*;
+    ///
+    /// let mut instance = StringSanitizer::from("DİYARBAKIR");
+    /// instance.to_lowercase_locale("tr");
+    /// assert_eq!(instance.get(), "diyarbakır");
+    /// ```
+    pub fn to_lowercase_locale<L: Into<Locale>>(&mut self, locale: L) -> &mut Self {
+        self.0 = locale::to_lowercase(&self.0, locale.into());
+        self
+    }
+    /// Convert string to upper case following the rules of the language,
+    /// eg. Turkish "i" becomes dotted "İ" and Greek loses its accents
+    pub fn to_uppercase_locale<L: Into<Locale>>(&mut self, locale: L) -> &mut Self {
+        self.0 = locale::to_uppercase(&self.0, locale.into());
+        self
+    }
+    /// Apply full Unicode default case folding, which maps strings that
+    /// only differ in case to the same value, eg. "Straße" and "STRASSE"
+    /// both become "strasse". Use this rather than
+    /// [`to_lowercase`](Self::to_lowercase) for caseless comparisons
+    pub fn case_fold(&mut self) -> &mut Self {
+        self.0 = caseless::default_case_fold_str(&self.0);
+        self
+    }
+    /// Convert string to a stable lookup key for identifiers such as
+    /// usernames: default ignorable characters (eg. zero width spaces)
+    /// are removed, and the result is NFKC normalized and case folded
+    /// (NFKC_Casefold)
+    pub fn identifier_key(&mut self) -> &mut Self {
+        self.0 = self
+            .0
+            .chars()
+            .filter(|character| !is_default_ignorable(*character))
+            .nfd()
+            .default_case_fold()
+            .nfkd()
+            .default_case_fold()
+            .nfkc()
+            .collect();
+        self
+    }
+    /// Transliterate the string to ASCII, mapping accented latin letters,
+    /// Cyrillic, Greek, CJK and other scripts to ASCII approximations, eg.
+    /// "Crème Москва" becomes "Creme Moskva". Characters which have no
+    /// approximation are removed
+    pub fn to_ascii(&mut self) -> &mut Self {
+        self.transliterate("")
+    }
+    /// Transliterate the string to ASCII like [`to_ascii`](Self::to_ascii),
+    /// replacing characters which have no approximation with `replacement`
+    pub fn transliterate(&mut self, replacement: &str) -> &mut Self {
+        self.0 = deunicode_with_tofu(&self.0, replacement);
+        self
+    }
+    /// Convert the string to a URL slug: it is transliterated to ASCII,
+    /// lowercased, and all runs of other characters become a single `-`,
+    /// eg. "Crème Brûlée: Recipe!" becomes "creme-brulee-recipe"
+    pub fn slugify(&mut self) -> &mut Self {
+        self.slugify_with("-", None)
+    }
+    /// Convert the string to a URL slug like [`slugify`](Self::slugify),
+    /// joining words with `separator`. With a `max` length, words that do
+    /// not fit are dropped, a first word longer than `max` is cut
+    ///
+    /// ```
+    /// use sanitizer::StringSanitizer;
+    ///
+    /// let mut sanitizer = StringSanitizer::from("Hello big world");
+    /// sanitizer.slugify_with("_", Some(12));
+    /// assert_eq!(sanitizer.get(), "hello_big");
+    /// ```
+    pub fn slugify_with(&mut self, separator: &str, max: Option<usize>) -> &mut Self {
+        self.0 = slugify(&self.0, separator, max);
+        self
+    }
     /// Convert string to camel case
     pub fn to_camel_case(&mut self) -> &mut Self {
         self.0 = self.0.to_lower_camel_case();
@@ -82,25 +311,543 @@ impl StringSanitizer {
         self.0 = self.0.to_shouty_snake_case();
         self
     }
-    /// Set the maximum lenght of the content
-    pub fn clamp_max(&mut self, limit: usize) -> &mut Self {
-        self.0.truncate(limit);
+    /// Convert string to title case
+    pub fn to_title_case(&mut self) -> &mut Self {
+        self.0 = self.0.to_title_case();
+        self
+    }
+    /// Convert string to pascal case (upper camel case)
+    pub fn to_pascal_case(&mut self) -> &mut Self {
+        self.0 = self.0.to_upper_camel_case();
+        self
+    }
+    /// Convert string to train case
+    pub fn to_train_case(&mut self) -> &mut Self {
+        self.0 = self.0.to_train_case();
         self
     }
+    /// Convert string to sentence case. Like the other case conversions,
+    /// the string is split into words on case changes and non alphanumeric
+    /// characters, which are dropped, so this is meant for identifiers and
+    /// labels rather than prose
+    ///
+    /// ```
+    /// use sanitizer::prelude::*;
+    ///
+    /// let mut instance = StringSanitizer::from("userIdValue");
+    /// instance.to_sentence_case();
+    /// assert_eq!(instance.get(), "User id value");
+    ///
+    /// let mut instance = StringSanitizer::from("hello, world! how are you?");
+    /// instance.to_sentence_case();
+    /// assert_eq!(instance.get(), "Hello world how are you");
+    /// ```
+    pub fn to_sentence_case(&mut self) -> &mut Self {
+        self.to_case_with_acronyms(WordCase::Sentence, &[])
+    }
+    /// Convert string to lower case words separated by spaces
+    pub fn to_lower_case_words(&mut self) -> &mut Self {
+        self.to_case_with_acronyms(WordCase::LowerWords, &[])
+    }
+    /// Convert string to the word based case, keeping the words which
+    /// match one of the acronyms (ignoring case) as they are given
+    ///
+    /// ```
+    /// use sanitizer::prelude::*;
+    /// use sanitizer::WordCase;
+    ///
+    /// let mut instance = StringSanitizer::from("http_request_id");
+    /// instance.to_case_with_acronyms(WordCase::Pascal, &["HTTP", "ID"]);
+    /// assert_eq!(instance.get(), "HTTPRequestID");
+    /// ```
+    pub fn to_case_with_acronyms(&mut self, case: WordCase, acronyms: &[&str]) -> &mut Self {
+        self.0 = to_case(&self.0, case, acronyms);
+        self
+    }
+    /// Capitalize a personal name: whitespace is collapsed, and every part
+    /// separated by a hyphen or apostrophe is title cased. The Mc and O'
+    /// prefixes are handled, Mac only for known Gaelic names such as
+    /// MacDonald so that Machado stays as it is, and particles such as
+    /// "van", "de" or "bin" stay lower case unless they start the name
+    ///
+    /// ```
+    /// use sanitizer::prelude::*;
+    ///
+    /// let mut instance = StringSanitizer::from(" ludwig VAN der o'brien-MCDONALD ");
+    /// instance.person_name();
+    /// assert_eq!(instance.get(), "Ludwig van der O'Brien-McDonald");
+    /// ```
+    pub fn person_name(&mut self) -> &mut Self {
+        self.person_name_with_exceptions(&[])
+    }
+    /// Like [`person_name`](Self::person_name), but names or parts of names
+    /// matching one of the exceptions (ignoring case) are written as the
+    /// exception, eg. "DeVito"
+    pub fn person_name_with_exceptions(&mut self, exceptions: &[&str]) -> &mut Self {
+        self.0 = person_name(&self.0, exceptions);
+        self
+    }
+    /// Normalize the string to Unicode Normalization Form C (canonical
+    /// composition). Strings which are already normalized are left as is
+    pub fn nfc(&mut self) -> &mut Self {
+        if is_nfc_quick(self.0.chars()) != IsNormalized::Yes {
+            self.0 = self.0.nfc().collect();
+        }
+        self
+    }
+    /// Normalize the string to Unicode Normalization Form D (canonical
+    /// decomposition). Strings which are already normalized are left as is
+    pub fn nfd(&mut self) -> &mut Self {
+        if is_nfd_quick(self.0.chars()) != IsNormalized::Yes {
+            self.0 = self.0.nfd().collect();
+        }
+        self
+    }
+    /// Normalize the string to Unicode Normalization Form KC (compatibility
+    /// composition). Strings which are already normalized are left as is
+    pub fn nfkc(&mut self) -> &mut Self {
+        if is_nfkc_quick(self.0.chars()) != IsNormalized::Yes {
+            self.0 = self.0.nfkc().collect();
+        }
+        self
+    }
+    /// Normalize the string to Unicode Normalization Form KD (compatibility
+    /// decomposition). Strings which are already normalized are left as is
+    pub fn nfkd(&mut self) -> &mut Self {
+        if is_nfkd_quick(self.0.chars()) != IsNormalized::Yes {
+            self.0 = self.0.nfkd().collect();
+        }
+        self
+    }
+    /// Set the maximum length of the content in bytes, rounded down
+    /// so that no character is split
+    pub fn clamp_max(&mut self, limit: usize) -> &mut Self {
+        self.truncate(limit, TruncateUnit::Bytes)
+    }
     /// Convert the phone number to the E164 International Standard
     pub fn e164(&mut self) -> &mut Self {
-        let phone_number = parse(None, &self.0);
-
-        if let Ok(number) = phone_number {
-            self.0 = number.format().mode(Mode::E164).to_string();
+        self.phone_number(None, Mode::E164)
+    }
+    /// Convert the phone number to the E164 International Standard,
+    /// reading numbers in national format as belonging to the region
+    pub fn e164_with_region(&mut self, region: Region) -> &mut Self {
+        self.phone_number(Some(region), Mode::E164)
+    }
+    /// Format the phone number with the given mode, reading numbers in
+    /// national format as belonging to the region if one is given.
+    /// The content is left untouched if it cannot be parsed
+    pub fn phone_number(&mut self, region: Option<Region>, format: PhoneFormat) -> &mut Self {
+        if let Ok(number) = parse(region, &self.0) {
+            self.0 = number.format().mode(format).to_string();
+        }
+        self
+    }
+    /// Strict version of [`e164`](Self::e164), see
+    /// [`try_phone_number`](Self::try_phone_number)
+    pub fn try_e164(&mut self, region: Option<Region>) -> Result<&mut Self, Error> {
+        self.try_phone_number(region, Mode::E164)
+    }
+    /// Strict version of [`phone_number`](Self::phone_number) which returns
+    /// an error instead of leaving the content untouched when the number
+    /// cannot be parsed or is not a valid, assigned number
+    pub fn try_phone_number(
+        &mut self,
+        region: Option<Region>,
+        format: PhoneFormat,
+    ) -> Result<&mut Self, Error> {
+        let number = parse_valid_number(region, &self.0)?;
+        self.0 = number.format().mode(format).to_string();
+        Ok(self)
+    }
+    /// Normalise an email address: surrounding whitespace is removed and
+    /// the domain is lowercased and IDNA encoded, eg. "John@München.de"
+    /// becomes "John@xn--mnchen-3ya.de". The local part is left as it is,
+    /// as it may be case sensitive, but it must be a valid dot separated
+    /// local part like "john.doe" or a quoted string like "\"a@b\"". The
+    /// content is left untouched if it is not a valid email address
+    pub fn email(&mut self) -> &mut Self {
+        if let Ok(email) = email(&self.0) {
+            self.0 = email;
+        }
+        self
+    }
+    /// Strict version of [`email`](Self::email) which returns
+    /// [`Error::InvalidEmail`] instead of leaving the content untouched
+    pub fn try_email(&mut self) -> Result<&mut Self, Error> {
+        self.0 = email(&self.0)?;
+        Ok(self)
+    }
+    /// Convert an email address to a key for finding duplicate accounts.
+    /// The address is normalised like [`email`](Self::email), the local
+    /// part is lowercased, and the rules of large providers are applied:
+    /// Gmail ignores dots and `+tags` and treats googlemail.com as
+    /// gmail.com, Outlook, iCloud, Fastmail and Proton ignore `+tags`, and
+    /// Yahoo ignores `-tags`. The content is left untouched if it is not a
+    /// valid email address. Store the result as a lookup key next to the
+    /// address, mail may not be delivered to it
+    ///
+    /// ```
+    /// use sanitizer::StringSanitizer;
+    ///
+    /// let mut instance = StringSanitizer::from(" John.Doe+news@GoogleMail.com ");
+    /// instance.email_canonical();
+    /// assert_eq!(instance.get(), "johndoe@gmail.com");
+    /// ```
+    pub fn email_canonical(&mut self) -> &mut Self {
+        if let Ok(email) = email_canonical(&self.0) {
+            self.0 = email;
+        }
+        self
+    }
+    /// Strict version of [`email_canonical`](Self::email_canonical) which
+    /// returns [`Error::InvalidEmail`] instead of leaving the content
+    /// untouched
+    pub fn try_email_canonical(&mut self) -> Result<&mut Self, Error> {
+        self.0 = email_canonical(&self.0)?;
+        Ok(self)
+    }
+    /// Normalise a domain or hostname: surrounding whitespace and a
+    /// trailing dot are removed, and it is lowercased and Unicode labels are
+    /// encoded with IDNA (punycode). Labels must be at most 63 bytes long
+    /// and the name at most 253 bytes, the content is left untouched if it
+    /// is not a valid hostname
+    ///
+    /// ```
+    /// use sanitizer::StringSanitizer;
+    ///
+    /// let mut instance = StringSanitizer::from(" München.DE. ");
+    /// instance.hostname();
+    /// assert_eq!(instance.get(), "xn--mnchen-3ya.de");
+    /// ```
+    pub fn hostname(&mut self) -> &mut Self {
+        if let Ok(hostname) = hostname(&self.0) {
+            self.0 = hostname;
+        }
+        self
+    }
+    /// Strict version of [`hostname`](Self::hostname) which returns
+    /// [`Error::InvalidHostname`] instead of leaving the content untouched
+    pub fn try_hostname(&mut self) -> Result<&mut Self, Error> {
+        self.0 = hostname(&self.0)?;
+        Ok(self)
+    }
+    /// Normalise a hostname like [`hostname`](Self::hostname) and decode
+    /// its punycode labels to Unicode for display, eg. "xn--mnchen-3ya.de"
+    /// becomes "münchen.de"
+    pub fn hostname_unicode(&mut self) -> &mut Self {
+        if let Ok(hostname) = hostname_unicode(&self.0) {
+            self.0 = hostname;
+        }
+        self
+    }
+    /// Strict version of [`hostname_unicode`](Self::hostname_unicode) which
+    /// returns [`Error::InvalidHostname`] instead of leaving the content
+    /// untouched
+    pub fn try_hostname_unicode(&mut self) -> Result<&mut Self, Error> {
+        self.0 = hostname_unicode(&self.0)?;
+        Ok(self)
+    }
+    /// Remove a leading `www.` of a hostname, in any case, as long as at
+    /// least two labels are left, eg. `www.com` is kept
+    pub fn strip_www(&mut self) -> &mut Self {
+        let stripped = strip_www(&self.0);
+        if stripped.len() != self.0.len() {
+            self.0 = stripped.to_owned();
+        }
+        self
+    }
+    /// Normalise a link with the default [`UrlOptions`]: `https://` is
+    /// added if the scheme is missing, the scheme and host are lowercased,
+    /// the host is IDNA encoded, default ports and `.` or `..` segments are
+    /// removed, percent encoding is normalised and tracking parameters like
+    /// `utm_source` are removed. Only http and https URLs without
+    /// credentials are accepted, other schemes such as `javascript:` are
+    /// not prefixed but rejected. The content is left untouched if it is
+    /// not a valid URL
+    pub fn url(&mut self) -> &mut Self {
+        self.url_with(&UrlOptions::default())
+    }
+    /// Normalise a link like [`url`](Self::url) with the given options
+    pub fn url_with(&mut self, options: &UrlOptions) -> &mut Self {
+        if let Ok(url) = url(&self.0, options) {
+            self.0 = url;
         }
         self
     }
-    /// Truncate the string with the given amount
+    /// Strict version of [`url`](Self::url) which returns
+    /// [`Error::InvalidUrl`] instead of leaving the content untouched
+    pub fn try_url(&mut self) -> Result<&mut Self, Error> {
+        self.try_url_with(&UrlOptions::default())
+    }
+    /// Strict version of [`url_with`](Self::url_with) which returns
+    /// [`Error::InvalidUrl`] instead of leaving the content untouched
+    pub fn try_url_with(&mut self, options: &UrlOptions) -> Result<&mut Self, Error> {
+        self.0 = url(&self.0, options)?;
+        Ok(self)
+    }
+    /// Parse the phone number and extract its E164 form along with the
+    /// country code, region, number type and validity. Returns `None` if
+    /// the content cannot be parsed as a phone number
+    ///
+    /// ```
+    /// use sanitizer::prelude::*;
+    /// use sanitizer::{PhoneType, Region};
+    ///
+    /// let instance = StringSanitizer::from("07400 123456");
+    /// let info = instance.phone_info(Some(Region::GB)).unwrap();
+    /// assert_eq!(info.e164, "+447400123456");
+    /// assert_eq!(info.country_code, 44);
+    /// assert_eq!(info.region, Some(Region::GB));
+    /// assert_eq!(info.number_type, PhoneType::Mobile);
+    /// assert!(info.is_valid);
+    /// ```
+    pub fn phone_info(&self, region: Option<Region>) -> Option<PhoneInfo> {
+        parse(region, &self.0)
+            .ok()
+            .map(|number| PhoneInfo::from(&number))
+    }
+    /// Truncate the string to the given amount of bytes, rounded down
+    /// so that no character is split
     pub fn cut(&mut self, amount: usize) -> &mut Self {
-        self.0.truncate(amount);
+        self.truncate(amount, TruncateUnit::Bytes)
+    }
+    /// Truncate the string to the limit, counted in the given unit
+    ///
+    /// ```
+    /// use sanitizer::prelude::*;
+    /// use sanitizer::TruncateUnit;
+    ///
+    /// let mut instance = StringSanitizer::from("héllo");
+    /// instance.truncate(2, TruncateUnit::Chars);
+    /// assert_eq!(instance.get(), "hé");
+    /// ```
+    pub fn truncate(&mut self, limit: usize, unit: TruncateUnit) -> &mut Self {
+        let end = prefix(&self.0, limit, unit).len();
+        self.0.truncate(end);
+        self
+    }
+    /// Truncate the string to the limit, counted in the given unit, and
+    /// end it with the ellipsis if it had to be cut. The ellipsis counts
+    /// towards the limit
+    pub fn truncate_with_ellipsis(
+        &mut self,
+        limit: usize,
+        unit: TruncateUnit,
+        ellipsis: &str,
+    ) -> &mut Self {
+        self.0 = truncate(&self.0, limit, unit, ellipsis);
+        self
+    }
+    /// Truncate the string to at most `max` characters without breaking
+    /// words and end it with the ellipsis if it had to be cut. Words are
+    /// found with Unicode word segmentation, so text without spaces (eg.
+    /// CJK) is cut between characters
+    ///
+    /// ```
+    /// use sanitizer::prelude::*;
+    ///
+    /// let mut instance = StringSanitizer::from("The quick brown fox");
+    /// instance.truncate_words(16, "…");
+    /// assert_eq!(instance.get(), "The quick brown…");
+    /// ```
+    pub fn truncate_words(&mut self, max: usize, ellipsis: &str) -> &mut Self {
+        self.0 = truncate_words(&self.0, max, ellipsis);
+        self
+    }
+    /// Escape the HTML reserved characters `&`, `<`, `>`, `"` and `'` so
+    /// the string can be placed in HTML text or a quoted attribute
+    pub fn html_escape(&mut self) -> &mut Self {
+        if let Cow::Owned(escaped) = encode_quoted_attribute(&self.0) {
+            self.0 = escaped;
+        }
+        self
+    }
+    /// Decode named, decimal and hexadecimal HTML entities. Decoding is
+    /// repeated until nothing changes, so double encoded input like
+    /// `&amp;amp;` becomes `&`
+    ///
+    /// ```
+    /// use sanitizer::StringSanitizer;
+    ///
+    /// let mut instance = StringSanitizer::from("Tom &amp;amp; Jerry &#x2764;&#65039;");
+    /// instance.html_unescape();
+    /// assert_eq!(instance.get(), "Tom & Jerry ❤️");
+    /// ```
+    pub fn html_unescape(&mut self) -> &mut Self {
+        while let Cow::Owned(decoded) = decode_html_entities(&self.0) {
+            if decoded == self.0 {
+                break;
+            }
+            self.0 = decoded;
+        }
+        self
+    }
+    /// Convert HTML to plain text: tags, comments and the content of
+    /// `script` and `style` are removed, entities are decoded, and block
+    /// elements like `p` or `li` start new lines. Whitespace is collapsed
+    /// like a browser does
+    ///
+    /// ```
+    /// use sanitizer::StringSanitizer;
+    ///
+    /// let mut instance = StringSanitizer::from("<h1>Hello</h1><p>Tom &amp; <b>Jerry</b></p>");
+    /// instance.strip_html();
+    /// assert_eq!(instance.get(), "Hello\n\nTom & Jerry");
+    /// ```
+    pub fn strip_html(&mut self) -> &mut Self {
+        self.0 = strip_html(&self.0);
+        self
+    }
+    /// Convert Markdown to plain text: emphasis, headings, code fences and
+    /// other markup are removed, links and images are replaced by their text
+    #[cfg(feature = "markdown")]
+    pub fn strip_markdown(&mut self) -> &mut Self {
+        self.0 = strip_markdown(&self.0);
+        self
+    }
+    /// Make user supplied HTML safe to embed, keeping only the tags and
+    /// attributes allowed by the policy. See [`HtmlPolicy`] for what is
+    /// removed with every policy
+    ///
+    /// ```
+    /// use sanitizer::{HtmlPolicy, StringSanitizer};
+    ///
+    /// let mut instance = StringSanitizer::from("<b onclick=\"steal()\">Hi</b><script>steal()</script>");
+    /// instance.html_sanitize(HtmlPolicy::BasicFormatting);
+    /// assert_eq!(instance.get(), "<b>Hi</b>");
+    /// ```
+    #[cfg(feature = "html")]
+    pub fn html_sanitize(&mut self, policy: HtmlPolicy) -> &mut Self {
+        self.0 = html_sanitize(&self.0, policy);
+        self
+    }
+    /// Replace all matches of the regex, `replacement` can refer to
+    /// capture groups like `$1` or `${name}`
+    ///
+    /// ```
+    /// use sanitizer::StringSanitizer;
+    /// use sanitizer::regex::Regex;
+    ///
+    /// let regex = Regex::new(r"(\d{4})-(\d{2})-(\d{2})").unwrap();
+    /// let mut instance = StringSanitizer::from("2024-01-31");
+    /// instance.regex_replace(&regex, "$3.$2.$1");
+    /// assert_eq!(instance.get(), "31.01.2024");
+    /// ```
+    #[cfg(feature = "regex")]
+    pub fn regex_replace(&mut self, regex: &Regex, replacement: &str) -> &mut Self {
+        if let Cow::Owned(replaced) = regex.replace_all(&self.0, replacement) {
+            self.0 = replaced;
+        }
+        self
+    }
+    /// Keep only the matches of the regex, joined together
+    #[cfg(feature = "regex")]
+    pub fn regex_keep(&mut self, regex: &Regex) -> &mut Self {
+        self.0 = regex
+            .find_iter(&self.0)
+            .map(|found| found.as_str())
+            .collect();
+        self
+    }
+    /// Replace the string with the capture group of the first match of the
+    /// regex, group 0 being the whole match. The string becomes empty if
+    /// the regex or the group does not match
+    #[cfg(feature = "regex")]
+    pub fn regex_extract(&mut self, regex: &Regex, group: usize) -> &mut Self {
+        self.0 = regex
+            .captures(&self.0)
+            .and_then(|captures| captures.get(group))
+            .map(|found| found.as_str().to_owned())
+            .unwrap_or_default();
         self
     }
+    /// Neutralise values which spreadsheets would execute as formulas when
+    /// opening a CSV export, following the OWASP guidance on CSV injection.
+    /// Values starting with `=`, `+`, `-`, `@`, a tab or a carriage return
+    /// are prefixed with `'`
+    pub fn csv_safe(&mut self) -> &mut Self {
+        self.csv_safe_with(CsvSafeOptions::default())
+    }
+    /// Neutralise formulas like [`csv_safe`](Self::csv_safe), with options
+    /// to output quoted CSV fields or to leave numbers unchanged
+    ///
+    /// ```
+    /// use sanitizer::{CsvSafeMode, CsvSafeOptions, StringSanitizer};
+    ///
+    /// let options = CsvSafeOptions {
+    ///     mode: CsvSafeMode::Quote,
+    ///     preserve_numbers: true,
+    /// };
+    /// let mut instance = StringSanitizer::from("=1+2");
+    /// instance.csv_safe_with(options);
+    /// assert_eq!(instance.get(), "\"'=1+2\"");
+    /// let mut instance = StringSanitizer::from("-5");
+    /// instance.csv_safe_with(options);
+    /// assert_eq!(instance.get(), "\"-5\"");
+    /// ```
+    pub fn csv_safe_with(&mut self, options: CsvSafeOptions) -> &mut Self {
+        self.0 = csv_safe(&self.0, options);
+        self
+    }
+    /// Make the string safe to write into a log line, so user input cannot
+    /// forge log entries: backslashes are escaped as `\\`, CR, LF and tab
+    /// as `\r`, `\n` and `\t` (other line breaks as `\n`), ANSI escape
+    /// sequences, control and bidirectional control characters are removed,
+    /// and the result is cut to [`LOG_SAFE_LIMIT`] characters ending with
+    /// "…". Use
+    /// [`LogSafe`](crate::LogSafe) to do the same when formatting, without
+    /// changing the value
+    pub fn log_safe(&mut self) -> &mut Self {
+        self.log_safe_with_limit(LOG_SAFE_LIMIT)
+    }
+    /// Make the string safe to write into a log line like
+    /// [`log_safe`](Self::log_safe), cutting it to at most `max` characters
+    pub fn log_safe_with_limit(&mut self, max: usize) -> &mut Self {
+        self.0 = log_safe(&self.0, max);
+        self
+    }
+    /// Make the string safe to use as the name of an uploaded file. Path
+    /// separators become `_` and `.` or `..` components are dropped, so
+    /// "../../etc/passwd" becomes "etc_passwd". Control characters and
+    /// characters Windows does not allow are removed, as are trailing dots
+    /// and spaces, and reserved device names like "CON.txt" get a `_`
+    /// prefix. Names longer than [`FILENAME_LIMIT`] bytes are cut, keeping
+    /// the extension. The result is empty if nothing is left
+    pub fn filename(&mut self) -> &mut Self {
+        self.filename_with_limit(FILENAME_LIMIT)
+    }
+    /// Sanitize the filename like [`filename`](Self::filename), cutting it
+    /// to at most `max` bytes
+    pub fn filename_with_limit(&mut self, max: usize) -> &mut Self {
+        self.0 = filename(&self.0, max);
+        self
+    }
+    /// Normalise a relative path: `/` and `\` separate components, `.`
+    /// and empty components are dropped, `..` removes the component before
+    /// it and each component is sanitized like a [`filename`](Self::filename).
+    /// Components are joined with `/` and the path never leaves its root,
+    /// `..` at the root is dropped
+    ///
+    /// ```
+    /// use sanitizer::StringSanitizer;
+    ///
+    /// let mut instance = StringSanitizer::from("/uploads/./2024/../../../etc/passwd");
+    /// instance.relative_path();
+    /// assert_eq!(instance.get(), "etc/passwd");
+    /// ```
+    pub fn relative_path(&mut self) -> &mut Self {
+        if let Ok(path) = relative_path(&self.0, true) {
+            self.0 = path;
+        }
+        self
+    }
+    /// Strict version of [`relative_path`](Self::relative_path) which
+    /// returns [`Error::PathEscapesRoot`] instead of dropping `..` at the
+    /// root
+    pub fn try_relative_path(&mut self) -> Result<&mut Self, Error> {
+        self.0 = relative_path(&self.0, false)?;
+        Ok(self)
+    }
     /// Call a custom function for sanitizing the string
     pub fn call<F>(&mut self, func: F) -> &mut Self
     where
@@ -167,8 +914,16 @@ mod test {
     }
 
     string_test!(trim, " Test   " => "Test");
+    string_test!(trim_start, " Test   " => "Test   ");
+    string_test!(trim_end, " Test   " => " Test");
+    string_test!(collapse_whitespace, " Hello \u{a0}\t World " => " Hello World ");
+    string_test!(strip_control, "a\u{0}b\tc\r\n\u{1b}[0m\u{85}d\u{7f}" => "abc[0md");
+    string_test!(strip_invisible, "a\u{200b}b\u{feff}c\u{ad}d\u{202e}👨\u{200d}👩\u{fe0f}" => "abcd👨\u{200d}👩\u{fe0f}");
+    string_test!(strip_bidi_controls, "\u{202e}abc\u{2066}\u{200b}" => "abc\u{200b}");
     string_test!(numeric, "Test123445Test" => "123445");
     string_test!(alphanumeric, "Hello,藏World&&" => "Hello藏World");
+    string_test!(ascii_numeric, "Test12\u{663}3" => "123");
+    string_test!(ascii_alphanumeric, "Hello,藏World&&" => "HelloWorld");
     string_test!(to_lowercase, "HELLO" => "hello");
     string_test!(to_uppercase, "hello" => "HELLO");
     string_test!(to_camel_case, "some_string" => "someString");
@@ -176,6 +931,70 @@ mod test {
     string_test!(to_kebab_case, "someString" => "some-string");
     string_test!(to_screaming_kebab_case, "someString" => "SOME-STRING");
     string_test!(to_screaming_snakecase, "someString" => "SOME_STRING");
+    string_test!(to_title_case, "someString" => "Some String");
+    string_test!(to_pascal_case, "some_string" => "SomeString");
+    string_test!(to_train_case, "someString" => "Some-String");
+    string_test!(to_sentence_case, "someString value" => "Some string value");
+    string_test!(to_lower_case_words, "SomeString" => "some string");
+    string_test!(case_fold, "Straße" => "strasse");
+    string_test!(identifier_key, "ＪＯＨＮ\u{200B}Straße" => "johnstrasse");
+    string_test!(html_escape, "<a href=\"x\">Tom & Jerry's</a>" => "&lt;a href=&quot;x&quot;&gt;Tom &amp; Jerry&#x27;s&lt;/a&gt;");
+    string_test!(html_unescape, "&lt;b&gt; &amp;amp;lt; &eacute;&#233;&#xE9; &unknown;" => "<b> < ééé &unknown;");
+    string_test!(slugify, "  Ünïcödé -- Title! " => "unicode-title");
+    string_test!(to_ascii, "Crème Brûlée, Москва, Αθήνα" => "Creme Brulee, Moskva, Athena");
+    string_test!(nfc, "Cafe\u{301}" => "Caf\u{e9}");
+    string_test!(nfd, "Caf\u{e9}" => "Cafe\u{301}");
+    string_test!(nfkc, "\u{fb01}ance\u{301}" => "fianc\u{e9}");
+    string_test!(nfkd, "\u{fb01}anc\u{e9}" => "fiance\u{301}");
+
+    #[test]
+    fn locale_case() {
+        let mut sanitizer = StringSanitizer::from("istanbul");
+        sanitizer.to_uppercase_locale(Locale::Turkish);
+        assert_eq!("İSTANBUL", *sanitizer);
+        sanitizer.to_lowercase_locale("az");
+        assert_eq!("istanbul", *sanitizer);
+        sanitizer
+            .to_uppercase_locale("en")
+            .to_lowercase_locale("tr");
+        assert_eq!("ıstanbul", sanitizer.get());
+    }
+
+    #[test]
+    fn transliterate() {
+        let mut sanitizer = StringSanitizer::from("藏 é\u{E000}");
+        sanitizer.transliterate("?");
+        assert_eq!("Cang e?", sanitizer.get());
+    }
+
+    #[test]
+    fn case_fold_matches() {
+        let fold = |content: &str| {
+            let mut sanitizer = StringSanitizer::from(content);
+            sanitizer.case_fold();
+            sanitizer.get()
+        };
+        assert_eq!(fold("Straße"), fold("STRASSE"));
+        assert_eq!(fold("ΣΊΣΥΦΟΣ"), fold("σίσυφοσ"));
+        assert_eq!(fold("σίσυφος"), fold("σίσυφοσ"));
+    }
+
+    #[test]
+    fn strip_control_except() {
+        let mut sanitizer = StringSanitizer::from("a\u{0}b\tc\r\n\u{7}");
+        sanitizer.strip_control_except("\n\t");
+        assert_eq!("ab\tc\n", sanitizer.get());
+    }
+
+    #[test]
+    fn multi_line_text() {
+        let mut sanitizer = StringSanitizer::from("Hello  \r\n \r\n\r\n\rWorld \u{2028}");
+        sanitizer
+            .normalize_newlines(NewlineStyle::Lf)
+            .strip_trailing_whitespace_per_line()
+            .max_consecutive_newlines(2);
+        assert_eq!("Hello\n\nWorld\n", sanitizer.get());
+    }
 
     #[test]
     fn e164() {
@@ -184,6 +1003,80 @@ mod test {
         assert_eq!("+15555551234", number.get());
     }
 
+    #[test]
+    fn e164_with_region() {
+        let mut number = StringSanitizer::from("020 7946 0018");
+        number.e164_with_region(Region::GB);
+        assert_eq!("+442079460018", number.get());
+    }
+
+    #[test]
+    fn national_number_without_region() {
+        let mut number = StringSanitizer::from("020 7946 0018");
+        number.e164();
+        assert_eq!("020 7946 0018", number.get());
+    }
+
+    #[test]
+    fn phone_number_formats() {
+        let mut number = StringSanitizer::from("+44 20 7946 0018");
+        number.phone_number(None, PhoneFormat::National);
+        assert_eq!("020 7946 0018", *number);
+        number.phone_number(Some(Region::GB), PhoneFormat::International);
+        assert_eq!("+44 20 7946 0018", *number);
+        number.phone_number(None, PhoneFormat::Rfc3966);
+        assert_eq!("tel:+44-20-7946-0018", number.get());
+    }
+
+    #[test]
+    fn try_e164() {
+        let mut number = StringSanitizer::from("020 7946 0018");
+        assert!(number.try_e164(Some(Region::GB)).is_ok());
+        assert_eq!("+442079460018", number.get());
+
+        let mut number = StringSanitizer::from("Not a Phone Number");
+        assert_eq!(
+            Err(Error::InvalidPhoneNumber),
+            number.try_e164(None).map(|_| ())
+        );
+        assert_eq!("Not a Phone Number", number.get());
+
+        let mut number = StringSanitizer::from("+1 (555) 555-1234");
+        assert_eq!(
+            Err(Error::UnassignedPhoneNumber),
+            number.try_e164(None).map(|_| ())
+        );
+        assert_eq!("+1 (555) 555-1234", number.get());
+
+        let mut number = StringSanitizer::from("+1 (454)");
+        assert_eq!(
+            Err(Error::InvalidPhoneNumber),
+            number.try_e164(None).map(|_| ())
+        );
+    }
+
+    #[test]
+    fn phone_info() {
+        let number = StringSanitizer::from("0800 890 011");
+        let info = number.phone_info(Some(Region::GB)).unwrap();
+        assert_eq!("+44800890011", info.e164);
+        assert_eq!(44, info.country_code);
+        assert_eq!(Some(Region::GB), info.region);
+        assert_eq!(crate::PhoneType::TollFree, info.number_type);
+        assert!(info.is_valid);
+
+        let number = StringSanitizer::from("+1 (555) 555-1234");
+        let info = number.phone_info(None).unwrap();
+        assert_eq!(1, info.country_code);
+        assert!(!info.is_valid);
+        assert!(info.is_possible);
+
+        assert_eq!(
+            None,
+            StringSanitizer::from("Not a Phone Number").phone_info(None)
+        );
+    }
+
     #[test]
     fn clamp_max() {
         let mut sanitizer = StringSanitizer::from("someString");
@@ -191,6 +1084,98 @@ mod test {
         assert_eq!("someStrin", sanitizer.get());
     }
 
+    #[test]
+    fn clamp_max_char_boundary() {
+        let mut sanitizer = StringSanitizer::from("héllo");
+        sanitizer.clamp_max(2);
+        assert_eq!("h", sanitizer.get());
+    }
+
+    #[test]
+    fn truncate() {
+        let mut sanitizer = StringSanitizer::from("héllo wörld");
+        sanitizer.truncate(8, TruncateUnit::Graphemes);
+        assert_eq!("héllo wö", *sanitizer);
+        sanitizer.truncate_with_ellipsis(6, TruncateUnit::Chars, "…");
+        assert_eq!("héllo…", sanitizer.get());
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn regex() {
+        let regex = Regex::new(r"[0-9]+").unwrap();
+        let mut sanitizer = StringSanitizer::from("a1b22c333");
+        sanitizer.regex_replace(&regex, "#");
+        assert_eq!("a#b#c#", sanitizer.get());
+        let mut sanitizer = StringSanitizer::from("a1b22c333");
+        sanitizer.regex_keep(&regex);
+        assert_eq!("122333", sanitizer.get());
+        let regex = Regex::new(r"order-(\d+)").unwrap();
+        let mut sanitizer = StringSanitizer::from("ref: order-42, order-7");
+        sanitizer.regex_extract(&regex, 1);
+        assert_eq!("42", *sanitizer);
+        sanitizer.regex_extract(&regex, 1);
+        assert_eq!("", sanitizer.get());
+    }
+
+    #[test]
+    fn try_relative_path() {
+        let mut sanitizer = StringSanitizer::from("a/../b");
+        assert!(sanitizer.try_relative_path().is_ok());
+        assert_eq!("b", *sanitizer);
+        let mut sanitizer = StringSanitizer::from("a/../../b");
+        assert_eq!(
+            Err(Error::PathEscapesRoot),
+            sanitizer.try_relative_path().map(|_| ())
+        );
+        assert_eq!("a/../../b", sanitizer.get());
+    }
+
+    #[test]
+    fn try_email() {
+        let mut sanitizer = StringSanitizer::from(" Jane@Example.COM ");
+        assert!(sanitizer.try_email().is_ok());
+        assert_eq!("Jane@example.com", *sanitizer);
+        let mut sanitizer = StringSanitizer::from("jane.example.com");
+        assert_eq!(Err(Error::InvalidEmail), sanitizer.try_email().map(|_| ()));
+        assert_eq!(
+            Err(Error::InvalidEmail),
+            sanitizer.try_email_canonical().map(|_| ())
+        );
+        sanitizer.email();
+        assert_eq!("jane.example.com", sanitizer.get());
+    }
+
+    #[test]
+    fn try_hostname() {
+        let mut sanitizer = StringSanitizer::from("WWW.Example.com.");
+        assert!(sanitizer.try_hostname().is_ok());
+        sanitizer.strip_www();
+        assert_eq!("example.com", *sanitizer);
+        let mut sanitizer = StringSanitizer::from("example..com");
+        assert_eq!(
+            Err(Error::InvalidHostname),
+            sanitizer.try_hostname().map(|_| ())
+        );
+        assert_eq!(
+            Err(Error::InvalidHostname),
+            sanitizer.try_hostname_unicode().map(|_| ())
+        );
+        sanitizer.hostname();
+        assert_eq!("example..com", sanitizer.get());
+    }
+
+    #[test]
+    fn try_url() {
+        let mut sanitizer = StringSanitizer::from("Example.com/?utm_medium=email");
+        assert!(sanitizer.try_url().is_ok());
+        assert_eq!("https://example.com/", *sanitizer);
+        let mut sanitizer = StringSanitizer::from("javascript:alert(1)");
+        assert_eq!(Err(Error::InvalidUrl), sanitizer.try_url().map(|_| ()));
+        sanitizer.url();
+        assert_eq!("javascript:alert(1)", sanitizer.get());
+    }
+
     #[test]
     fn wrong_phone_number() {
         let mut sanitizer = StringSanitizer::from("Not a Phone Number");
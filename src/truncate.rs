@@ -0,0 +1,139 @@
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// The unit in which truncation limits are counted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TruncateUnit {
+    /// UTF-8 bytes, rounded down to the nearest char boundary
+    Bytes,
+    /// Unicode scalar values
+    Chars,
+    /// Extended grapheme clusters, ie. user perceived characters
+    Graphemes,
+    /// Columns taken up in a terminal, wide (eg. CJK) characters count as two
+    Width,
+}
+
+impl TruncateUnit {
+    /// Measure the length of the string in this unit
+    pub fn len(&self, content: &str) -> usize {
+        match self {
+            Self::Bytes => content.len(),
+            Self::Chars => content.chars().count(),
+            Self::Graphemes => content.graphemes(true).count(),
+            Self::Width => content.width(),
+        }
+    }
+}
+
+// the longest prefix of content which fits into limit
+pub(crate) fn prefix(content: &str, limit: usize, unit: TruncateUnit) -> &str {
+    let end = match unit {
+        TruncateUnit::Bytes => {
+            let mut end = limit.min(content.len());
+            while !content.is_char_boundary(end) {
+                end -= 1;
+            }
+            end
+        }
+        TruncateUnit::Chars => content
+            .char_indices()
+            .nth(limit)
+            .map_or(content.len(), |(index, _)| index),
+        TruncateUnit::Graphemes => content
+            .grapheme_indices(true)
+            .nth(limit)
+            .map_or(content.len(), |(index, _)| index),
+        TruncateUnit::Width => {
+            let mut width = 0;
+            content
+                .grapheme_indices(true)
+                .find(|(_, grapheme)| {
+                    width += grapheme.width();
+                    width > limit
+                })
+                .map_or(content.len(), |(index, _)| index)
+        }
+    };
+    &content[..end]
+}
+
+// truncate content to limit, replacing the end with the ellipsis if the
+// content had to be cut. The ellipsis counts towards the limit
+pub(crate) fn truncate(content: &str, limit: usize, unit: TruncateUnit, ellipsis: &str) -> String {
+    if unit.len(content) <= limit {
+        return content.to_owned();
+    }
+    let ellipsis_len = unit.len(ellipsis);
+    if ellipsis_len > limit {
+        return prefix(content, limit, unit).to_owned();
+    }
+    let mut truncated = prefix(content, limit - ellipsis_len, unit).to_owned();
+    truncated.push_str(ellipsis);
+    truncated
+}
+
+// truncate content to at most limit chars (including the ellipsis) on a
+// word boundary, as defined by UAX #29. A first word longer than the limit
+// is cut in the middle since there's no earlier boundary
+pub(crate) fn truncate_words(content: &str, limit: usize, ellipsis: &str) -> String {
+    if content.chars().count() <= limit {
+        return content.to_owned();
+    }
+    let ellipsis_len = ellipsis.chars().count();
+    if ellipsis_len > limit {
+        return prefix(content, limit, TruncateUnit::Chars).to_owned();
+    }
+    let budget = limit - ellipsis_len;
+    let mut end = 0;
+    let mut length = 0;
+    for (index, word) in content.split_word_bound_indices() {
+        length += word.chars().count();
+        if length > budget {
+            break;
+        }
+        end = index + word.len();
+    }
+    let mut truncated = content[..end].trim_end().to_owned();
+    if truncated.is_empty() {
+        truncated = prefix(content, budget, TruncateUnit::Chars).to_owned();
+    }
+    truncated.push_str(ellipsis);
+    truncated
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn units() {
+        let content = "he\u{301}llo 世界";
+        assert_eq!("h", prefix("héllo", 2, TruncateUnit::Bytes));
+        assert_eq!("he", prefix(content, 2, TruncateUnit::Chars));
+        assert_eq!("he\u{301}", prefix(content, 2, TruncateUnit::Graphemes));
+        assert_eq!("he\u{301}llo 世", prefix(content, 8, TruncateUnit::Width));
+        assert_eq!("he\u{301}llo ", prefix(content, 7, TruncateUnit::Width));
+        assert_eq!(content, prefix(content, 100, TruncateUnit::Chars));
+    }
+
+    #[test]
+    fn ellipsis() {
+        assert_eq!("hel…", truncate("hello", 4, TruncateUnit::Chars, "…"));
+        assert_eq!("hello", truncate("hello", 5, TruncateUnit::Chars, "…"));
+        assert_eq!("h", truncate("hello", 1, TruncateUnit::Chars, "..."));
+        assert_eq!("h…", truncate("hello", 4, TruncateUnit::Bytes, "…"));
+    }
+
+    #[test]
+    fn words() {
+        let content = "The quick brown fox";
+        assert_eq!("The quick…", truncate_words(content, 15, "…"));
+        assert_eq!("The quick brown…", truncate_words(content, 16, "…"));
+        assert_eq!(content, truncate_words(content, 19, "…"));
+        assert_eq!("The", truncate_words(content, 5, ""));
+        assert_eq!("Th…", truncate_words("Thesaurus", 3, "…"));
+        assert_eq!("東京都…", truncate_words("東京都に住んでいます", 4, "…"));
+        assert_eq!("he", truncate_words("hello wonderful world", 2, "..."));
+    }
+}
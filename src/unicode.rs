@@ -0,0 +1,137 @@
+// ranges of the Default_Ignorable_Code_Point property, from
+// DerivedCoreProperties.txt
+static DEFAULT_IGNORABLE: [(char, char); 17] = [
+    ('\u{00AD}', '\u{00AD}'),
+    ('\u{034F}', '\u{034F}'),
+    ('\u{061C}', '\u{061C}'),
+    ('\u{115F}', '\u{1160}'),
+    ('\u{17B4}', '\u{17B5}'),
+    ('\u{180B}', '\u{180F}'),
+    ('\u{200B}', '\u{200F}'),
+    ('\u{202A}', '\u{202E}'),
+    ('\u{2060}', '\u{206F}'),
+    ('\u{3164}', '\u{3164}'),
+    ('\u{FE00}', '\u{FE0F}'),
+    ('\u{FEFF}', '\u{FEFF}'),
+    ('\u{FFA0}', '\u{FFA0}'),
+    ('\u{FFF0}', '\u{FFF8}'),
+    ('\u{1BCA0}', '\u{1BCA3}'),
+    ('\u{1D173}', '\u{1D17A}'),
+    ('\u{E0000}', '\u{E0FFF}'),
+];
+
+// characters which should be ignored when comparing or rendering text,
+// eg. zero width spaces, soft hyphens and variation selectors
+pub(crate) fn is_default_ignorable(character: char) -> bool {
+    in_ranges(&DEFAULT_IGNORABLE, character)
+}
+
+static VARIATION_SELECTORS: [(char, char); 2] =
+    [('\u{FE00}', '\u{FE0F}'), ('\u{E0100}', '\u{E01EF}')];
+
+const ZERO_WIDTH_JOINER: char = '\u{200D}';
+const CANCEL_TAG: char = '\u{E007F}';
+
+// longest tag sequence of a valid emoji flag, a region and a subdivision
+// code such as "gbsct"
+const MAX_TAG_SPEC_LEN: usize = 6;
+
+// the explicit directional formatting characters of UAX #9
+static BIDI_CONTROLS: [(char, char); 4] = [
+    ('\u{061C}', '\u{061C}'),
+    ('\u{200E}', '\u{200F}'),
+    ('\u{202A}', '\u{202E}'),
+    ('\u{2066}', '\u{2069}'),
+];
+
+// a visible character which variation selectors and tags can apply to
+fn is_base(character: char) -> bool {
+    !is_default_ignorable(character) && !character.is_control() && !character.is_whitespace()
+}
+
+// tags spelling the subdivision of an emoji flag, lowercase letters and
+// digits
+fn is_tag_spec(character: char) -> bool {
+    ('\u{E0030}'..='\u{E0039}').contains(&character)
+        || ('\u{E0061}'..='\u{E007A}').contains(&character)
+}
+
+// the length of the tag sequence starting the chars, eg. the subdivision
+// of 🏴 followed by the cancel tag
+fn tag_sequence_len(chars: &[char]) -> Option<usize> {
+    let spec_len = chars
+        .iter()
+        .take_while(|character| is_tag_spec(**character))
+        .count();
+    let valid =
+        (1..=MAX_TAG_SPEC_LEN).contains(&spec_len) && chars.get(spec_len) == Some(&CANCEL_TAG);
+    valid.then_some(spec_len + 1)
+}
+
+// see StringSanitizer::strip_invisible. A single variation selector is
+// kept after a visible character, and tags only when they form a complete
+// emoji tag sequence after a non ASCII character, so they cannot be used
+// to hide text
+pub(crate) fn strip_invisible(content: &str) -> String {
+    let chars: Vec<char> = content.chars().collect();
+    let mut visible = String::with_capacity(content.len());
+    let mut previous = None;
+    let mut index = 0;
+    while index < chars.len() {
+        let character = chars[index];
+        if !is_default_ignorable(character) || character == ZERO_WIDTH_JOINER {
+            visible.push(character);
+        } else if in_ranges(&VARIATION_SELECTORS, character) {
+            if previous.is_some_and(is_base) {
+                visible.push(character);
+            }
+        } else if let Some(len) = tag_sequence_len(&chars[index..])
+            .filter(|_| previous.is_some_and(|base: char| is_base(base) && !base.is_ascii()))
+        {
+            visible.extend(&chars[index..index + len]);
+            previous = Some(CANCEL_TAG);
+            index += len;
+            continue;
+        }
+        previous = Some(character);
+        index += 1;
+    }
+    visible
+}
+
+pub(crate) fn is_bidi_control(character: char) -> bool {
+    in_ranges(&BIDI_CONTROLS, character)
+}
+
+pub(crate) fn in_ranges(ranges: &[(char, char)], character: char) -> bool {
+    ranges
+        .iter()
+        .any(|(start, end)| (*start..=*end).contains(&character))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn emoji() {
+        let family = "👨\u{200D}👩\u{200D}👧";
+        assert_eq!(family, strip_invisible(family));
+        let scotland = "🏴\u{E0067}\u{E0062}\u{E0073}\u{E0063}\u{E0074}\u{E007F}";
+        assert_eq!(scotland, strip_invisible(scotland));
+        assert_eq!("❤\u{FE0F}", strip_invisible("❤\u{FE0F}"));
+        assert_eq!("葛\u{E0100}", strip_invisible("葛\u{E0100}"));
+    }
+
+    #[test]
+    fn smuggling() {
+        assert_eq!("ab", strip_invisible("a\u{E0041}b"));
+        assert_eq!(
+            "🏴",
+            strip_invisible("🏴\u{E0067}\u{E0062}\u{E0073}\u{E0063}\u{E0074}")
+        );
+        assert_eq!("a", strip_invisible("a\u{E0067}\u{E0062}\u{E007F}"));
+        assert_eq!("😀\u{FE0F}", strip_invisible("😀\u{FE0F}\u{FE01}\u{E0101}"));
+        assert_eq!("x", strip_invisible("\u{FE0F}x"));
+    }
+}
@@ -0,0 +1,187 @@
+/// Line ending used by
+/// [`StringSanitizer::normalize_newlines`](crate::StringSanitizer::normalize_newlines)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NewlineStyle {
+    /// `\n`, used by Unix
+    Lf,
+    /// `\r\n`, used by Windows and many network protocols
+    Crlf,
+}
+
+impl NewlineStyle {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Lf => "\n",
+            Self::Crlf => "\r\n",
+        }
+    }
+}
+
+// characters which end a line, \r\n counts as a single line break
+pub(crate) fn is_line_break(character: char) -> bool {
+    matches!(
+        character,
+        '\n' | '\r' | '\u{0B}' | '\u{0C}' | '\u{85}' | '\u{2028}' | '\u{2029}'
+    )
+}
+
+// replace every run of whitespace with a single replacement, chosen from the
+// number of line breaks in the run
+fn replace_whitespace_runs<F>(content: &str, replacement: F) -> String
+where
+    F: Fn(usize) -> &'static str,
+{
+    let mut replaced = String::with_capacity(content.len());
+    let mut chars = content.chars().peekable();
+    while let Some(character) = chars.next() {
+        if !character.is_whitespace() {
+            replaced.push(character);
+            continue;
+        }
+        let mut line_breaks = 0;
+        let mut current = character;
+        loop {
+            if is_line_break(current) {
+                line_breaks += 1;
+                if current == '\r' {
+                    chars.next_if_eq(&'\n');
+                }
+            }
+            match chars.next_if(|next| next.is_whitespace()) {
+                Some(next) => current = next,
+                None => break,
+            }
+        }
+        replaced.push_str(replacement(line_breaks));
+    }
+    replaced
+}
+
+// see StringSanitizer::collapse_whitespace
+pub(crate) fn collapse_whitespace(content: &str) -> String {
+    replace_whitespace_runs(content, |_| " ")
+}
+
+// see StringSanitizer::normalize_whitespace
+pub(crate) fn normalize_whitespace(content: &str, keep_newlines: bool) -> String {
+    replace_whitespace_runs(content, |line_breaks| match line_breaks {
+        _ if !keep_newlines => " ",
+        0 => " ",
+        1 => "\n",
+        _ => "\n\n",
+    })
+}
+
+// see StringSanitizer::normalize_newlines
+pub(crate) fn normalize_newlines(content: &str, style: NewlineStyle) -> String {
+    let mut normalized = String::with_capacity(content.len());
+    let mut chars = content.chars().peekable();
+    while let Some(character) = chars.next() {
+        match character {
+            '\r' => {
+                chars.next_if_eq(&'\n');
+                normalized.push_str(style.as_str());
+            }
+            '\n' | '\u{85}' | '\u{2028}' | '\u{2029}' => normalized.push_str(style.as_str()),
+            character => normalized.push(character),
+        }
+    }
+    normalized
+}
+
+// see StringSanitizer::max_consecutive_newlines
+pub(crate) fn max_consecutive_newlines(content: &str, max: usize) -> String {
+    let mut limited = String::with_capacity(content.len());
+    let mut consecutive = 0;
+    let mut chars = content.chars().peekable();
+    while let Some(character) = chars.next() {
+        if !is_line_break(character) {
+            consecutive = 0;
+            limited.push(character);
+            continue;
+        }
+        consecutive += 1;
+        let crlf = character == '\r' && chars.next_if_eq(&'\n').is_some();
+        if consecutive <= max {
+            limited.push(character);
+            if crlf {
+                limited.push('\n');
+            }
+        }
+    }
+    limited
+}
+
+// see StringSanitizer::strip_trailing_whitespace_per_line
+pub(crate) fn strip_trailing_whitespace_per_line(content: &str) -> String {
+    let mut stripped = String::with_capacity(content.len());
+    let mut pending = String::new();
+    for character in content.chars() {
+        if is_line_break(character) {
+            pending.clear();
+            stripped.push(character);
+        } else if character.is_whitespace() {
+            pending.push(character);
+        } else {
+            stripped.push_str(&pending);
+            pending.clear();
+            stripped.push(character);
+        }
+    }
+    stripped
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn collapse() {
+        assert_eq!(
+            " a b c ",
+            collapse_whitespace("\t a\u{a0}\u{3000}b\r\n c  ")
+        );
+        assert_eq!("abc", collapse_whitespace("abc"));
+    }
+
+    #[test]
+    fn normalize() {
+        let content = "First  line\r\n\tsecond\u{a0}line \n\r\n\n Next\u{2029}paragraph";
+        assert_eq!(
+            "First line\nsecond line\n\nNext\nparagraph",
+            normalize_whitespace(content, true)
+        );
+        assert_eq!(
+            "First line second line Next paragraph",
+            normalize_whitespace(content, false)
+        );
+    }
+
+    #[test]
+    fn newlines() {
+        let content = "a\r\nb\rc\nd\u{2028}e\u{2029}f";
+        assert_eq!(
+            "a\nb\nc\nd\ne\nf",
+            normalize_newlines(content, NewlineStyle::Lf)
+        );
+        assert_eq!(
+            "a\r\nb\r\nc\r\nd\r\ne\r\nf",
+            normalize_newlines(content, NewlineStyle::Crlf)
+        );
+    }
+
+    #[test]
+    fn consecutive_newlines() {
+        assert_eq!("a\n\nb\nc", max_consecutive_newlines("a\n\n\n\nb\nc", 2));
+        assert_eq!("a\r\nb", max_consecutive_newlines("a\r\n\r\n\r\nb", 1));
+        assert_eq!("ab", max_consecutive_newlines("a\n\nb", 0));
+    }
+
+    #[test]
+    fn trailing_whitespace() {
+        assert_eq!(
+            "a\n  b\r\n\nc",
+            strip_trailing_whitespace_per_line("a \t\n  b  \r\n \nc  ")
+        );
+    }
+}